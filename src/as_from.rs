@@ -1,9 +1,11 @@
 use crate::Either;
+use std::{rc::Rc, sync::Arc};
 
 /// A trait to convert things inside other types.
 /// By default, this allows conversions essentially
 /// being: [Option]\<T> -> [Option]\<U> or
 /// [Result]<T, E> -> [Result]<U, R>
+/// as well as doing the same for [Vec], [Box], [Arc] and [Rc]
 /// 
 /// The main benefit here is that you don't need to
 /// take the value out of the type in order to convert it.
@@ -125,6 +127,35 @@ impl<T: From<U>, U, E: From<R>, R> AsFrom<Result<U, R>> for Result<T, E> {
         }
     }
 }
+///```
+/// # use abes_nice_things::AsFrom;
+/// let strings = <Vec<String>>::as_from(vec!["Never", "gonna"]);
+/// assert_eq!(strings, vec![String::from("Never"), String::from("gonna")]);
+///```
+impl<T: From<U>, U> AsFrom<Vec<U>> for Vec<T> {
+    fn as_from(value: Vec<U>) -> Self {
+        return value.into_iter().map(T::from).collect()
+    }
+}
+impl<T: From<U>, U> AsFrom<Box<U>> for Box<T> {
+    fn as_from(value: Box<U>) -> Self {
+        return Box::new(T::from(*value))
+    }
+}
+/// Only clones the inner value if there
+/// are other [Arcs](Arc) pointing to it.
+impl<T: From<U>, U: Clone> AsFrom<Arc<U>> for Arc<T> {
+    fn as_from(value: Arc<U>) -> Self {
+        return Arc::new(T::from(Arc::unwrap_or_clone(value)))
+    }
+}
+/// Only clones the inner value if there
+/// are other [Rcs](Rc) pointing to it.
+impl<T: From<U>, U: Clone> AsFrom<Rc<U>> for Rc<T> {
+    fn as_from(value: Rc<U>) -> Self {
+        return Rc::new(T::from(Rc::unwrap_or_clone(value)))
+    }
+}
 /// A trait for attempting to convert within another type.
 /// This shares a similar relationship to [AsFrom]
 /// as [TryFrom] does to [From].
@@ -235,4 +266,60 @@ impl<T: TryInto<U>, U, E: TryInto<R>, R> AsTryInto<Result<U, R>> for Result<T, E
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    // Counts how many times it has been cloned
+    struct Counted<'a>(&'a Cell<usize>);
+    impl<'a> Clone for Counted<'a> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get()+1);
+            Counted(self.0)
+        }
+    }
+    impl<'a> From<Counted<'a>> for usize {
+        fn from(value: Counted<'a>) -> Self {
+            value.0.get()
+        }
+    }
+    #[test]
+    fn boxed() {
+        assert_eq!(<Box<String>>::as_from(Box::new("Bees")), Box::new(String::from("Bees")));
+    }
+    #[test]
+    fn arc_unique() {
+        let clones = Cell::new(0);
+        <Arc<usize>>::as_from(Arc::new(Counted(&clones)));
+        assert_eq!(clones.get(), 0, "Uniquely owned value was cloned");
+    }
+    #[test]
+    fn arc_shared() {
+        let original: Arc<&str> = Arc::new("Bees");
+        assert_eq!(<Arc<String>>::as_from(original.clone()), Arc::new(String::from("Bees")));
+        assert_eq!(*original, "Bees");
+        let clones = Cell::new(0);
+        let original = Arc::new(Counted(&clones));
+        <Arc<usize>>::as_from(original.clone());
+        assert_eq!(clones.get(), 1, "Shared value was not cloned");
+        assert_eq!(Arc::strong_count(&original), 1);
+    }
+    #[test]
+    fn rc_unique() {
+        let clones = Cell::new(0);
+        <Rc<usize>>::as_from(Rc::new(Counted(&clones)));
+        assert_eq!(clones.get(), 0, "Uniquely owned value was cloned");
+    }
+    #[test]
+    fn rc_shared() {
+        let original: Rc<&str> = Rc::new("Bees");
+        assert_eq!(<Rc<String>>::as_from(original.clone()), Rc::new(String::from("Bees")));
+        assert_eq!(*original, "Bees");
+        let clones = Cell::new(0);
+        let original = Rc::new(Counted(&clones));
+        <Rc<usize>>::as_from(original.clone());
+        assert_eq!(clones.get(), 1, "Shared value was not cloned");
+        assert_eq!(Rc::strong_count(&original), 1);
+    }
+}
@@ -0,0 +1,219 @@
+use std::io::{Read, Write};
+use crate::{FromBinary, ToBinary};
+/// A growable list of bools which stores each one as a single bit,
+/// making it take up an eighth of the memory of a [Vec]\<bool>
+/// (plus at most 7 bytes of unused space at the end).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct BitVec {
+    inner: Vec<u64>,
+    len: usize,
+}
+impl BitVec {
+    pub const fn new() -> Self {
+        BitVec {
+            inner: Vec::new(),
+            len: 0
+        }
+    }
+    /// Creates an empty [BitVec] with space for
+    /// at least the given number of bools.
+    pub fn with_capacity(capacity: usize) -> Self {
+        BitVec {
+            inner: Vec::with_capacity(capacity.div_ceil(64)),
+            len: 0
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn push(&mut self, value: bool) {
+        if self.len % 64 == 0 {
+            self.inner.push(0);
+        }
+        self.len += 1;
+        self.set(self.len-1, value);
+    }
+    /// Gets the bool at the index,
+    /// panicking if it is out of bounds.
+    pub fn get(&self, index: usize) -> bool {
+        self.check_index(index);
+        self.inner[index/64] & (1 << (index%64)) != 0
+    }
+    /// Sets the bool at the index,
+    /// panicking if it is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.check_index(index);
+        if value {
+            self.inner[index/64] |= 1 << (index%64);
+        }
+        else {
+            self.inner[index/64] &= !(1 << (index%64));
+        }
+    }
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            parent: self,
+            index: 0,
+            back_index: self.len
+        }
+    }
+    fn check_index(&self, index: usize) {
+        if index >= self.len {
+            panic!("Index out of bounds: the len is {} but the index is {index}", self.len)
+        }
+    }
+}
+impl std::fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl From<Vec<bool>> for BitVec {
+    fn from(value: Vec<bool>) -> Self {
+        value.into_iter().collect()
+    }
+}
+impl From<BitVec> for Vec<bool> {
+    fn from(value: BitVec) -> Self {
+        value.iter().collect()
+    }
+}
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bit_vec = BitVec::new();
+        bit_vec.extend(iter);
+        bit_vec
+    }
+}
+impl Extend<bool> for BitVec {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value)
+        }
+    }
+}
+impl<'a> IntoIterator for &'a BitVec {
+    type Item = bool;
+    type IntoIter = Iter<'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+/// Reads the number of bools, then only
+/// as many bytes as are needed to store them.
+impl FromBinary for BitVec {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        let len = usize::from_binary(binary);
+        let mut bytes = vec![0; len.div_ceil(8)];
+        binary.read_exact(&mut bytes).unwrap();
+        let mut inner = Vec::with_capacity(len.div_ceil(64));
+        for chunk in bytes.chunks(8) {
+            let mut word: [u8; 8] = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            inner.push(u64::from_le_bytes(word));
+        }
+        BitVec {
+            inner,
+            len
+        }
+    }
+}
+/// Writes the number of bools, then only
+/// as many bytes as are needed to store them.
+impl ToBinary for BitVec {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.len.to_binary(write);
+        let mut bytes: Vec<u8> = Vec::with_capacity(self.inner.len()*8);
+        for word in self.inner.iter() {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        write.write_all(&bytes[..self.len.div_ceil(8)]).unwrap()
+    }
+}
+/// [Iter](Iterator) struct for [BitVec]
+pub struct Iter<'a> {
+    parent: &'a BitVec,
+    index: usize,
+    back_index: usize,
+}
+impl<'a> Iterator for Iter<'a> {
+    type Item = bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None
+        }
+        self.index += 1;
+        Some(self.parent.get(self.index-1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.back_index <= self.index {
+            return None
+        }
+        self.back_index -= 1;
+        Some(self.parent.get(self.back_index))
+    }
+}
+impl<'a> ExactSizeIterator for Iter<'a> {
+    fn len(&self) -> usize {
+        self.back_index - self.index
+    }
+}
+impl<'a> std::iter::FusedIterator for Iter<'a> {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn push_get() {
+        let mut bit_vec = BitVec::new();
+        for index in 0..200 {
+            bit_vec.push(index % 3 == 0);
+        }
+        assert_eq!(bit_vec.len(), 200);
+        for index in 0..200 {
+            assert_eq!(bit_vec.get(index), index % 3 == 0, "Wrong value at index: {index}");
+        }
+    }
+    #[test]
+    fn set() {
+        let mut bit_vec = BitVec::from(vec![false; 70]);
+        bit_vec.set(65, true);
+        bit_vec.set(3, true);
+        bit_vec.set(3, false);
+        assert!(bit_vec.get(65));
+        assert!(!bit_vec.get(3));
+        assert_eq!(bit_vec.iter().filter(|value| *value).count(), 1);
+    }
+    #[test]
+    #[should_panic(expected = "the index is 5")]
+    fn out_of_bounds() {
+        BitVec::from(vec![true; 5]).get(5);
+    }
+    #[test]
+    fn vec_conversion() {
+        let source = vec![true, false, false, true, true];
+        let bit_vec = BitVec::from(source.clone());
+        assert_eq!(bit_vec.iter().rev().collect::<Vec<bool>>(), [true, true, false, false, true]);
+        assert_eq!(Vec::<bool>::from(bit_vec), source);
+    }
+    #[test]
+    fn binary() {
+        let bit_vec: BitVec = (0..77).map(|index| index % 2 == 0).collect();
+        let mut buf: Vec<u8> = Vec::new();
+        bit_vec.clone().to_binary(&mut buf);
+        assert_eq!(buf.len(), std::mem::size_of::<usize>()+10, "Wrote unneeded bytes");
+        assert_eq!(BitVec::from_binary(&mut buf.as_slice()), bit_vec);
+    }
+    #[test]
+    fn memory() {
+        let bit_vec = BitVec::from(vec![true; 6400]);
+        assert_eq!(bit_vec.inner.len()*std::mem::size_of::<u64>(), 6400/8);
+    }
+}
@@ -0,0 +1,247 @@
+use std::io::{Read, Write};
+use std::mem::MaybeUninit;
+use crate::{FromBinary, ToBinary};
+/// A fixed capacity queue stored entirely in an array,
+/// meaning that it never allocates.
+/// When it is full, [pushing](CircularBuffer::push)
+/// overwrites the oldest value.
+pub struct CircularBuffer<T, const N: usize> {
+    inner: [MaybeUninit<T>; N],
+    // Index of the oldest value
+    head: usize,
+    len: usize,
+}
+impl<T, const N: usize> CircularBuffer<T, N> {
+    pub const fn new() -> Self {
+        CircularBuffer {
+            inner: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+    /// Adds the value as the newest,
+    /// giving back the oldest value
+    /// if it had to be overwritten.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if N == 0 {
+            return Some(value)
+        }
+        if self.is_full() {
+            // The tail is where the head is, so
+            // the oldest gets replaced and the head moves on
+            let old = std::mem::replace(&mut self.inner[self.head], MaybeUninit::new(value));
+            self.head = (self.head+1) % N;
+            return Some(unsafe { old.assume_init() })
+        }
+        self.inner[(self.head+self.len) % N] = MaybeUninit::new(value);
+        self.len += 1;
+        None
+    }
+    /// Removes and gives the oldest value.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None
+        }
+        let value = std::mem::replace(&mut self.inner[self.head], MaybeUninit::uninit());
+        self.head = (self.head+1) % N;
+        self.len -= 1;
+        Some(unsafe { value.assume_init() })
+    }
+    /// Gets the value at the index,
+    /// where 0 is the oldest value.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None
+        }
+        Some(unsafe { self.inner[(self.head+index) % N].assume_init_ref() })
+    }
+    /// Gets the value at the index,
+    /// where 0 is the oldest value.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None
+        }
+        Some(unsafe { self.inner[(self.head+index) % N].assume_init_mut() })
+    }
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+    /// Iterates from the oldest value to the newest.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            parent: self,
+            index: 0,
+            back_index: self.len
+        }
+    }
+}
+impl<T, const N: usize> Drop for CircularBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+impl<T, const N: usize> Default for CircularBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Clone, const N: usize> Clone for CircularBuffer<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for CircularBuffer<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T: PartialEq, const N: usize> PartialEq for CircularBuffer<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+/// Pushes each value in order, so
+/// only the last N values will be kept.
+impl<T, const N: usize> FromIterator<T> for CircularBuffer<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = CircularBuffer::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+impl<T, const N: usize> Extend<T> for CircularBuffer<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a CircularBuffer<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+/// Reads the number of values, then the values from oldest to newest.
+/// Panics if there are more than N values.
+impl<T: FromBinary, const N: usize> FromBinary for CircularBuffer<T, N> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        let len = usize::from_binary(binary);
+        if len > N {
+            panic!("Expected at most {N} values but found: {len}")
+        }
+        let mut buffer = CircularBuffer::new();
+        for _ in 0..len {
+            buffer.push(T::from_binary(binary));
+        }
+        buffer
+    }
+}
+/// Writes the number of values, then the values from oldest to newest.
+impl<T: ToBinary, const N: usize> ToBinary for CircularBuffer<T, N> {
+    fn to_binary(mut self, write: &mut dyn Write) {
+        self.len.to_binary(write);
+        while let Some(value) = self.pop_front() {
+            value.to_binary(write)
+        }
+    }
+}
+/// [Iter](Iterator) struct for [CircularBuffer]
+pub struct Iter<'a, T, const N: usize> {
+    parent: &'a CircularBuffer<T, N>,
+    index: usize,
+    back_index: usize,
+}
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None
+        }
+        self.index += 1;
+        self.parent.get(self.index-1)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+impl<'a, T, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.back_index <= self.index {
+            return None
+        }
+        self.back_index -= 1;
+        self.parent.get(self.back_index)
+    }
+}
+impl<'a, T, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
+    fn len(&self) -> usize {
+        self.back_index - self.index
+    }
+}
+impl<'a, T, const N: usize> std::iter::FusedIterator for Iter<'a, T, N> {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn push_pop() {
+        let mut buffer: CircularBuffer<usize, 3> = CircularBuffer::new();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.push(1), None);
+        assert_eq!(buffer.push(2), None);
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(buffer.pop_front(), None);
+    }
+    #[test]
+    fn overwrite() {
+        let mut buffer: CircularBuffer<usize, 3> = CircularBuffer::new();
+        buffer.extend([1, 2, 3]);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push(4), Some(1), "Oldest value was not overwritten");
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.iter().copied().collect::<Vec<usize>>(), [2, 3, 4]);
+        assert_eq!(buffer.get(0), Some(&2));
+        assert_eq!(buffer.get(3), None);
+    }
+    #[test]
+    fn wrap_around() {
+        let mut buffer: CircularBuffer<usize, 4> = CircularBuffer::new();
+        for value in 0..10 {
+            buffer.push(value);
+            if value % 3 == 0 {
+                buffer.pop_front();
+            }
+        }
+        assert_eq!(buffer.iter().rev().copied().collect::<Vec<usize>>(), [9, 8, 7]);
+    }
+    #[test]
+    fn drops_values() {
+        let counter = std::rc::Rc::new(());
+        {
+            let mut buffer: CircularBuffer<std::rc::Rc<()>, 3> = CircularBuffer::new();
+            for _ in 0..5 {
+                buffer.push(counter.clone());
+            }
+            assert_eq!(std::rc::Rc::strong_count(&counter), 4);
+        }
+        assert_eq!(std::rc::Rc::strong_count(&counter), 1, "Values were leaked");
+    }
+    #[test]
+    fn binary() {
+        let buffer: CircularBuffer<u32, 4> = (0..6).collect();
+        let mut buf: Vec<u8> = Vec::new();
+        buffer.clone().to_binary(&mut buf);
+        assert_eq!(CircularBuffer::<u32, 4>::from_binary(&mut buf.as_slice()), buffer);
+    }
+}
@@ -1,4 +1,7 @@
 use std::io::{Read, Write};
+use std::borrow::Cow;
+use std::num::{Saturating, Wrapping};
+use std::ops::Bound;
 /// This trait is designed to allow for easier conversion from binary
 /// in a defined and consistent way.
 pub trait FromBinary {
@@ -43,6 +46,25 @@ num_helper!(i128);
 num_helper!(isize);
 num_helper!(f32);
 num_helper!(f64);
+macro_rules! wrapper_helper {
+    ($wrapper: ident) => {
+        impl<T: FromBinarySized> FromBinarySized for $wrapper<T> {
+            const LEN: usize = T::LEN;
+        }
+        impl<T: FromBinary> FromBinary for $wrapper<T> {
+            fn from_binary(binary: &mut dyn Read) -> Self {
+                $wrapper(T::from_binary(binary))
+            }
+        }
+        impl<T: ToBinary> ToBinary for $wrapper<T> {
+            fn to_binary(self, write: &mut dyn Write) {
+                self.0.to_binary(write)
+            }
+        }
+    }
+}
+wrapper_helper!(Wrapping);
+wrapper_helper!(Saturating);
 impl FromBinary for bool {
     fn from_binary(binary: &mut dyn Read) -> Self {
         let mut buf: [u8; 1] = [0];
@@ -89,6 +111,50 @@ impl ToBinary for String {
         write.write_all(self.as_bytes()).unwrap();
     }
 }
+impl ToBinary for &str {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.len().to_binary(write);
+        write.write_all(self.as_bytes()).unwrap();
+    }
+}
+/// Always gives a [Cow::Owned] because the data
+/// is read into a new [String], so there is
+/// nothing to borrow from.
+///```
+/// # use abes_nice_things::{FromBinary, ToBinary};
+/// # use std::borrow::Cow;
+/// let mut buf: Vec<u8> = Vec::new();
+/// Cow::Borrowed("Bees").to_binary(&mut buf);
+/// let read = Cow::<'static, str>::from_binary(&mut buf.as_slice());
+/// assert!(matches!(read, Cow::Owned(_)));
+/// assert_eq!(read, "Bees");
+///```
+impl FromBinary for Cow<'static, str> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        Cow::Owned(String::from_binary(binary))
+    }
+}
+impl ToBinary for Cow<'_, str> {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.as_ref().to_binary(write)
+    }
+}
+/// Always gives a [Cow::Owned] for the same reason as
+/// [Cow]\<str>. Uses the same format as [Vec]\<u8>.
+impl FromBinary for Cow<'static, [u8]> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        let len = usize::from_binary(binary);
+        let mut buf = vec![0; len];
+        binary.read_exact(&mut buf).unwrap();
+        Cow::Owned(buf)
+    }
+}
+impl ToBinary for Cow<'_, [u8]> {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.len().to_binary(write);
+        write.write_all(&self).unwrap();
+    }
+}
 impl<T: FromBinary> FromBinary for Vec<T> {
     fn from_binary(binary: &mut dyn Read) -> Self {
         let len = usize::from_binary(binary);
@@ -133,4 +199,71 @@ impl<T: ToBinary> ToBinary for Option<T> {
             }
         }
     }
-}
\ No newline at end of file
+}
+impl<T: FromBinary> FromBinary for Bound<T> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        match u8::from_binary(binary) {
+            0 => Bound::Included(T::from_binary(binary)),
+            1 => Bound::Excluded(T::from_binary(binary)),
+            2 => Bound::Unbounded,
+            other => panic!("Invalid Bound variant: {other}")
+        }
+    }
+}
+impl<T: ToBinary> ToBinary for Bound<T> {
+    fn to_binary(self, write: &mut dyn Write) {
+        match self {
+            Bound::Included(value) => {
+                0_u8.to_binary(write);
+                value.to_binary(write);
+            }
+            Bound::Excluded(value) => {
+                1_u8.to_binary(write);
+                value.to_binary(write);
+            }
+            Bound::Unbounded => {
+                2_u8.to_binary(write)
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn bound() {
+        for bound in [Bound::Included(5_u32), Bound::Excluded(7), Bound::Unbounded] {
+            let mut buf: Vec<u8> = Vec::new();
+            bound.to_binary(&mut buf);
+            assert_eq!(Bound::<u32>::from_binary(&mut buf.as_slice()), bound);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Invalid Bound variant: 3")]
+    fn bound_invalid() {
+        Bound::<u32>::from_binary(&mut [3_u8].as_slice());
+    }
+    #[test]
+    fn cow_bytes() {
+        let bytes: Vec<u8> = vec![1, 2, 3, 255];
+        let mut vec_buf: Vec<u8> = Vec::new();
+        bytes.clone().to_binary(&mut vec_buf);
+        let mut cow_buf: Vec<u8> = Vec::new();
+        Cow::Borrowed(bytes.as_slice()).to_binary(&mut cow_buf);
+        assert_eq!(cow_buf, vec_buf, "Format differs from Vec<u8>");
+        let read = Cow::<'static, [u8]>::from_binary(&mut cow_buf.as_slice());
+        assert!(matches!(read, Cow::Owned(_)));
+        assert_eq!(read, bytes);
+    }
+    #[test]
+    fn wrappers() {
+        let mut buf: Vec<u8> = Vec::new();
+        Wrapping(-3_i16).to_binary(&mut buf);
+        assert_eq!(Wrapping::<i16>::from_binary(&mut buf.as_slice()), Wrapping(-3));
+        let mut buf: Vec<u8> = Vec::new();
+        Saturating(9000_u32).to_binary(&mut buf);
+        assert_eq!(Saturating::<u32>::from_binary(&mut buf.as_slice()), Saturating(9000));
+        assert_eq!(Wrapping::<i16>::LEN, i16::LEN);
+        assert_eq!(Saturating::<u32>::LEN, u32::LEN);
+    }
+}
@@ -0,0 +1,125 @@
+use std::io::{Read, Write};
+use std::ops::Bound;
+use crate::{FromBinary, ToBinary};
+/// A range of values between two [Bounds](Bound)
+/// where either end can be included, excluded, or unbounded.
+///```
+/// # use abes_nice_things::Interval;
+/// let interval = Interval::half_open(0.0, 1.0);
+/// assert!(interval.contains(&0.0));
+/// assert!(!interval.contains(&1.0));
+/// assert!(interval.overlaps(&Interval::closed(0.5, 2.0)));
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: Bound<T>,
+    pub end: Bound<T>,
+}
+impl<T: PartialOrd> Interval<T> {
+    pub const fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Interval {
+            start,
+            end
+        }
+    }
+    /// [start, end]
+    pub const fn closed(start: T, end: T) -> Self {
+        Interval::new(Bound::Included(start), Bound::Included(end))
+    }
+    /// (start, end)
+    pub const fn open(start: T, end: T) -> Self {
+        Interval::new(Bound::Excluded(start), Bound::Excluded(end))
+    }
+    /// [start, end)
+    pub const fn half_open(start: T, end: T) -> Self {
+        Interval::new(Bound::Included(start), Bound::Excluded(end))
+    }
+    pub fn contains(&self, value: &T) -> bool {
+        let after_start = match &self.start {
+            Bound::Included(start) => start <= value,
+            Bound::Excluded(start) => start < value,
+            Bound::Unbounded => true
+        };
+        let before_end = match &self.end {
+            Bound::Included(end) => value <= end,
+            Bound::Excluded(end) => value < end,
+            Bound::Unbounded => true
+        };
+        after_start && before_end
+    }
+    /// Whether there are no values between the bounds.
+    /// This only compares the bounds, so an open
+    /// interval like (1, 2) is not empty, even for integers.
+    pub fn is_empty(&self) -> bool {
+        !Self::in_order(&self.start, &self.end)
+    }
+    /// Whether there is any value in both intervals.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && Self::in_order(&self.start, &other.end)
+            && Self::in_order(&other.start, &self.end)
+    }
+    // Whether there is space for a value between the start and end
+    fn in_order(start: &Bound<T>, end: &Bound<T>) -> bool {
+        match (start, end) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => return true,
+            (Bound::Included(start), Bound::Included(end)) => return start <= end,
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) => return start < end
+        }
+    }
+}
+impl<T: FromBinary> FromBinary for Interval<T> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        Interval {
+            start: Bound::from_binary(binary),
+            end: Bound::from_binary(binary)
+        }
+    }
+}
+impl<T: ToBinary> ToBinary for Interval<T> {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.start.to_binary(write);
+        self.end.to_binary(write);
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn contains() {
+        let closed = Interval::closed(1, 3);
+        let open = Interval::open(1, 3);
+        let half_open = Interval::half_open(1, 3);
+        for (value, expected) in [(0, [false; 3]), (1, [true, false, true]), (2, [true; 3]), (3, [true, false, false])] {
+            assert_eq!(
+                [closed.contains(&value), open.contains(&value), half_open.contains(&value)],
+                expected,
+                "Wrong result for: {value}"
+            );
+        }
+        assert!(Interval::new(Bound::Unbounded, Bound::Excluded(0)).contains(&i32::MIN));
+    }
+    #[test]
+    fn is_empty() {
+        assert!(!Interval::closed(1, 1).is_empty());
+        assert!(Interval::half_open(1, 1).is_empty());
+        assert!(Interval::open(2, 1).is_empty());
+        assert!(!Interval::new(Bound::Excluded(5), Bound::Unbounded).is_empty());
+    }
+    #[test]
+    fn overlaps() {
+        assert!(Interval::closed(0, 2).overlaps(&Interval::closed(2, 4)));
+        assert!(!Interval::half_open(0, 2).overlaps(&Interval::closed(2, 4)));
+        assert!(Interval::open(0.0, 5.0).overlaps(&Interval::closed(1.0, 2.0)));
+        assert!(!Interval::closed(0, 1).overlaps(&Interval::open(3, 3)));
+        assert!(Interval::new(Bound::Unbounded, Bound::Included(0)).overlaps(&Interval::closed(-5, -4)));
+    }
+    #[test]
+    fn binary() {
+        let interval = Interval::new(Bound::Excluded(-4_i64), Bound::Unbounded);
+        let mut buf: Vec<u8> = Vec::new();
+        interval.to_binary(&mut buf);
+        assert_eq!(Interval::from_binary(&mut buf.as_slice()), interval);
+    }
+}
@@ -3,11 +3,17 @@ pub mod as_from;
 pub use as_from::{AsFrom, AsInto, AsTryFrom, AsTryInto};
 pub mod from_binary;
 pub use from_binary::{FromBinary, FromBinarySized, ToBinary};
+pub mod bit_vec;
+pub use bit_vec::BitVec;
+pub mod circular_buffer;
+pub use circular_buffer::CircularBuffer;
+pub mod interval;
+pub use interval::Interval;
 
 use rand::distributions::uniform::{SampleRange, SampleUniform};
 use serde::{Serialize, Deserialize};
 use std::{
-    io::stdin,
+    io::{stdin, Read, Write},
     ops::{Range, RangeBounds, RangeInclusive},
     sync::{Mutex, MutexGuard},
     fmt::Debug
@@ -19,8 +25,13 @@ pub mod prelude {
     pub use crate::{
         assert_pattern,
         assert_pattern_ne,
+        matches_pattern,
+        not_matches_pattern,
+        try_pattern,
+        unwrap_pattern,
         debug,
         debug_println,
+        debug_eprintln,
         input,
         AsFrom,
         AsInto,
@@ -52,34 +63,149 @@ macro_rules! debug_println {
         println!("[DEBUG] {}", format_args!($($arg)*));
     }
 }
+/// The same as [debug_println] except that
+/// it prints to stderr using [eprintln]
+/// instead of to stdout.
+#[macro_export]
+macro_rules! debug_eprintln {
+    () => {
+        #[cfg(debug_assertions)]
+        eprintln!("[DEBUG]")
+    };
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        eprintln!("[DEBUG] {}", format_args!($($arg)*));
+    }
+}
+/// Panics if the item does not match the pattern.
+/// The item can be any expression, which
+/// will only be evaluated once.
+///```
+/// # use abes_nice_things::assert_pattern;
+/// assert_pattern!("5".parse::<usize>(), Ok(_));
+///```
 #[macro_export]
 macro_rules! assert_pattern {
-    ($item: ident, $pattern: pat_param) => {
+    ($item: expr, $pattern: pat_param) => {
         if let $pattern = $item {}
         else {
             panic!("Item did not match variant");
         }
     };
-    ($item: ident, $pattern: pat_param, $($arg:tt)*) => {
+    ($item: expr, $pattern: pat_param, $($arg:tt)*) => {
         if let $pattern = $item {}
         else {
             panic!("{}", format_args!($($arg)*));
         }
     };
 }
+/// Panics if the item matches the pattern.
+/// The item can be any expression, which
+/// will only be evaluated once.
 #[macro_export]
 macro_rules! assert_pattern_ne {
-    ($item: ident, $pattern: pat_param) => {
+    ($item: expr, $pattern: pat_param) => {
         if let $pattern = $item {
             panic!("Item matched variant");
         }
     };
-    ($item: ident, $pattern: pat_param, $($arg:tt)*) => {
+    ($item: expr, $pattern: pat_param, $($arg:tt)*) => {
         if let $pattern = $item {
             panic!("{}", format_args!($($arg)*));
         }
     };
 }
+/// Gives whether or not the item matches the pattern
+/// without panicking. Essentially [matches] but named
+/// to go with [assert_pattern].
+///```
+/// # use abes_nice_things::matches_pattern;
+/// let value: Option<usize> = Some(5);
+/// assert!(matches_pattern!(value, Some(_)));
+/// assert!(!matches_pattern!("five".parse::<usize>(), Ok(_)));
+///```
+#[macro_export]
+macro_rules! matches_pattern {
+    ($item: expr, $pattern: pat_param) => {
+        if let $pattern = $item {
+            true
+        }
+        else {
+            false
+        }
+    };
+}
+/// The inverse of [matches_pattern],
+/// giving true if the item does NOT match the pattern.
+#[macro_export]
+macro_rules! not_matches_pattern {
+    ($item: expr, $pattern: pat_param) => {
+        !$crate::matches_pattern!($item, $pattern)
+    };
+}
+/// Gives [Ok] if the item matches the pattern and [Err] if
+/// it doesn't, so that it can be used with the ? operator.
+/// Without a message, the error is (), but if you give it
+/// a message then that will be the error instead.
+///```
+/// # use abes_nice_things::try_pattern;
+/// fn check(first: Option<usize>, second: Result<usize, ()>) -> Result<(), &'static str> {
+///     try_pattern!(first, Some(_), "first was None")?;
+///     try_pattern!(second, Ok(_), "second was Err")?;
+///     Ok(())
+/// }
+/// # fn main() {
+/// assert_eq!(check(Some(1), Ok(2)), Ok(()));
+/// assert_eq!(check(None, Ok(2)), Err("first was None"));
+/// # }
+///```
+#[macro_export]
+macro_rules! try_pattern {
+    ($item: expr, $pattern: pat_param) => {
+        if let $pattern = $item {
+            Ok::<(), ()>(())
+        }
+        else {
+            Err::<(), ()>(())
+        }
+    };
+    ($item: expr, $pattern: pat_param, $msg: literal) => {
+        if let $pattern = $item {
+            Ok::<(), &'static str>(())
+        }
+        else {
+            Err::<(), &'static str>($msg)
+        }
+    };
+}
+/// Gives the result of the expression after the => if the item
+/// matches the pattern, and panics if it doesn't.
+/// The expression can use anything bound by the pattern.
+///```
+/// # use abes_nice_things::unwrap_pattern;
+/// let value: Result<usize, &str> = Ok(5);
+/// assert_eq!(unwrap_pattern!(value, Ok(x) => x*2), 10);
+///```
+///```should_panic
+/// # use abes_nice_things::unwrap_pattern;
+/// let value: Result<usize, &str> = Err("five");
+/// unwrap_pattern!(value, Ok(x) => x, "Expected Ok but found: {value:?}");
+///```
+#[macro_export]
+macro_rules! unwrap_pattern {
+    ($item: expr, $pattern: pat_param => $binding: expr) => {
+        match $item {
+            $pattern => $binding,
+            _ => panic!("Item did not match pattern: {}", stringify!($pattern))
+        }
+    };
+    ($item: expr, $pattern: pat_param => $binding: expr, $($arg:tt)*) => {
+        match $item {
+            $pattern => $binding,
+            _ => panic!("{}", format_args!($($arg)*))
+        }
+    };
+}
 /// A macro which will only run code
 /// when the crate is not compiled
 /// with '--release'
@@ -148,6 +274,23 @@ impl<'a, T> OnceLockMethod<'a, T> {
     pub fn init(&self) {
         *self.inner.lock().unwrap() = Some((self.method)());
     }
+    /// Runs the method to set the data
+    /// only if it hasn't been set yet.
+    /// Returns whether or not it initialized it.
+    /// Also, this is blocking.
+    pub fn try_init(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.is_some() {
+            return false
+        }
+        *inner = Some((self.method)());
+        return true
+    }
+    /// Removes the data so that it
+    /// can be initialized again.
+    pub fn reset(&self) {
+        *self.inner.lock().unwrap() = None;
+    }
     /// Gets the data,
     /// or if there is no data yet,
     /// returns None.
@@ -254,6 +397,7 @@ impl<'a, T> Drop for OnPanic<'a, T> {
 /// the y and z axes, but they do not need to have the same
 /// lengths as each other. So x could be 3 long, while
 /// z could be 5 long.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct NVec<T, const N: usize> {
     inner: Vec<T>,
     lengths: [usize; N],
@@ -267,6 +411,45 @@ impl<T, const N: usize> NVec<T, N> {
             lengths: [0; N]
         }
     }
+    /// Creates an [NVec] with the given lengths
+    /// where every value is a clone of the given one.
+    pub fn from_elem(value: T, lengths: [usize; N]) -> Self where T: Clone {
+        NVec {
+            inner: vec![value; lengths.iter().product()],
+            lengths
+        }
+    }
+    /// Creates an [NVec] with the given lengths out of the
+    /// values in the [Vec], where the last index changes fastest.
+    /// Gives an error if the number of values is not
+    /// the product of the lengths.
+    pub fn from_vec(data: Vec<T>, lengths: [usize; N]) -> Result<Self, error::ShapeError> {
+        let expected: usize = lengths.iter().product();
+        if data.len() != expected {
+            return Err(error::ShapeError {
+                expected,
+                found: data.len()
+            })
+        }
+        Ok(NVec {
+            inner: data,
+            lengths
+        })
+    }
+    /// Sets every value to a clone of the given one.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.inner.fill(value)
+    }
+    pub fn lengths(&self) -> [usize; N] {
+        self.lengths
+    }
+    /// The total number of values.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
     pub fn to_vec(&self) -> &Vec<T> {
         &self.inner
     }
@@ -275,10 +458,8 @@ impl<T, const N: usize> NVec<T, N> {
         self.lengths = *lengths;
     }
     /// Assumes the correct number of indexes are given
+    /// and that they are all in bounds.
     fn get_index(&self, indexes: &[usize]) -> usize {
-        assert_eq!(
-            indexes.len(), self.lengths.len(),
-            "Incorrect number of indexes given:\nexpected: {}, got:{}", self.lengths.len(), indexes.len());
         let mut sum: usize = self.lengths.iter().product();
         let mut target: usize = 0;
         for index in 0..N {
@@ -289,38 +470,578 @@ impl<T, const N: usize> NVec<T, N> {
         }
         return target
     }
-    /// Gets a reference to the value at the given position.
-    pub fn get(&self, indexes: &[usize; N]) -> &T {
-        &self.inner[
-            self.get_index(indexes)
-        ]
+    fn get_index_checked(&self, indexes: &[usize]) -> Option<usize> {
+        if indexes.len() != N {
+            return None
+        }
+        if indexes.iter().zip(self.lengths.iter()).any(|(index, len)| index >= len) {
+            return None
+        }
+        return Some(self.get_index(indexes))
     }
-    /// Same as [get](NVec::get) but without checks that
-    /// the correct number of indexes have been given.
-    pub unsafe fn get_slice(&self, indexes: &[usize]) -> &T {
-        &self.inner[
-            self.get_index(indexes)
-        ]
+    /// Panics with the axis which was out of bounds
+    /// instead of giving an [Option].
+    fn get_index_or_panic(&self, indexes: &[usize]) -> usize {
+        assert_eq!(
+            indexes.len(), N,
+            "Incorrect number of indexes given:\nexpected: {}, got:{}", N, indexes.len());
+        for axis in 0..N {
+            if indexes[axis] >= self.lengths[axis] {
+                panic!(
+                    "Index out of bounds on axis {axis}: the len is {} but the index is {}",
+                    self.lengths[axis], indexes[axis]
+                )
+            }
+        }
+        return self.get_index(indexes)
     }
-    /// Gets a mutable reference to the value at the given position.
-    pub fn get_mut(&mut self, indexes: &[usize; N]) -> &mut T {
+    /// Gets a reference to the value at the given position,
+    /// or [None] if any of the indexes are out of bounds.
+    pub fn get(&self, indexes: &[usize; N]) -> Option<&T> {
+        let index: usize = self.get_index_checked(indexes)?;
+        return Some(&self.inner[index])
+    }
+    /// Gets a mutable reference to the value at the given position,
+    /// or [None] if any of the indexes are out of bounds.
+    pub fn get_mut(&mut self, indexes: &[usize; N]) -> Option<&mut T> {
         // index needs to be defined before we start getting the actual value
         // because otherwise it will be using an immutable reference to
         // self while getting a mutable reference to something owned by self
+        let index: usize = self.get_index_checked(indexes)?;
+        return Some(&mut self.inner[index])
+    }
+    /// Same as [get](NVec::get) but without any checks.
+    /// # Safety
+    /// There must be exactly N indexes and
+    /// each one must be less than the length of its axis.
+    pub unsafe fn get_unchecked(&self, indexes: &[usize]) -> &T {
         let index: usize = self.get_index(indexes);
-        return &mut self.inner[index]
+        return unsafe { self.inner.get_unchecked(index) }
     }
-    /// Same as [get_mut](NVec::get_mut) but without checking that
-    /// the correct number of indexes have been given.
-    pub unsafe fn get_slice_mut(&mut self, indexes: &[usize]) -> &mut T {
+    /// Same as [get_mut](NVec::get_mut) but without any checks.
+    /// # Safety
+    /// There must be exactly N indexes and
+    /// each one must be less than the length of its axis.
+    pub unsafe fn get_unchecked_mut(&mut self, indexes: &[usize]) -> &mut T {
         let index: usize = self.get_index(indexes);
-        return &mut self.inner[index]
+        return unsafe { self.inner.get_unchecked_mut(index) }
+    }
+    /// Changes the length of one axis, keeping every value
+    /// at the same position. If the axis grows, the new
+    /// positions are filled with clones of the given value.
+    pub fn resize_axis(&mut self, axis: usize, new_len: usize, fill: T) where T: Clone {
+        self.check_axis(axis);
+        let old_len: usize = self.lengths[axis];
+        if new_len <= old_len {
+            return self.shrink_axis(axis, new_len)
+        }
+        // Each block holds everything at one position of the
+        // earlier axes, which is what needs to be spread out
+        let outer: usize = self.lengths[..axis].iter().product();
+        let stride: usize = self.lengths[axis+1..].iter().product();
+        let old_block: usize = old_len*stride;
+        let new_block: usize = new_len*stride;
+        self.inner.resize(outer*new_block, fill.clone());
+        // Going back to front means that values are
+        // only ever moved to places which have already been moved out of
+        for block in (0..outer).rev() {
+            for index in (0..old_block).rev() {
+                self.inner.swap(block*old_block+index, block*new_block+index);
+            }
+            for index in old_block..new_block {
+                self.inner[block*new_block+index] = fill.clone();
+            }
+        }
+        self.lengths[axis] = new_len;
+    }
+    /// Shortens one axis, keeping every remaining
+    /// value at the same position.
+    /// Does nothing if the axis is already shorter.
+    pub fn truncate_axis(&mut self, axis: usize, new_len: usize) {
+        self.check_axis(axis);
+        if new_len < self.lengths[axis] {
+            self.shrink_axis(axis, new_len)
+        }
+    }
+    fn shrink_axis(&mut self, axis: usize, new_len: usize) {
+        let stride: usize = self.lengths[axis+1..].iter().product();
+        let old_block: usize = self.lengths[axis]*stride;
+        let new_block: usize = new_len*stride;
+        if old_block != 0 {
+            let mut index: usize = 0;
+            self.inner.retain(|_| {
+                index += 1;
+                (index-1) % old_block < new_block
+            });
+        }
+        self.lengths[axis] = new_len;
+    }
+    /// Iterates through the values which are one away
+    /// along a single axis (up to 2N of them),
+    /// skipping any which would be out of bounds.
+    pub fn neighbors(&self, index: [usize; N]) -> impl Iterator<Item = ([usize; N], &T)> {
+        (0..N*2).filter_map(move |neighbor| {
+            let mut offsets: [isize; N] = [0; N];
+            offsets[neighbor/2] = match neighbor % 2 {
+                0 => -1,
+                _ => 1
+            };
+            self.get_offset(index, offsets)
+        })
+    }
+    /// Same as [neighbors](NVec::neighbors) but also
+    /// includes diagonals (up to 3^N - 1 of them).
+    pub fn neighbors_moore(&self, index: [usize; N]) -> impl Iterator<Item = ([usize; N], &T)> {
+        (0..3_usize.pow(N as u32)).filter_map(move |mut neighbor| {
+            // Each axis is a digit in base 3
+            let mut offsets: [isize; N] = [0; N];
+            for offset in offsets.iter_mut().rev() {
+                *offset = (neighbor % 3) as isize - 1;
+                neighbor /= 3;
+            }
+            if offsets == [0; N] {
+                return None
+            }
+            self.get_offset(index, offsets)
+        })
+    }
+    fn get_offset(&self, mut index: [usize; N], offsets: [isize; N]) -> Option<([usize; N], &T)> {
+        for axis in 0..N {
+            index[axis] = index[axis].checked_add_signed(offsets[axis])?;
+        }
+        Some((index, self.get(&index)?))
+    }
+    fn check_axis(&self, axis: usize) {
+        if axis >= N {
+            panic!("Axis out of bounds: there are {N} axes but the axis is {axis}")
+        }
+    }
+    /// Iterates through the values in order
+    /// where the last index changes fastest.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+    /// Iterates through the values in order
+    /// where the last index changes fastest.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+    /// Same as [iter](NVec::iter) but also gives the position of each value.
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T, N> {
+        IndexedIter {
+            inner: self.inner.iter().enumerate(),
+            lengths: self.lengths
+        }
     }
     pub fn clear(&mut self) {
         self.inner = Vec::new();
         self.lengths = [0; N];
     }
 }
+/// Shows the values as nested rows for up to 3 dimensions,
+/// and as a single list for more than that.
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for NVec<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lengths: &[usize] = match N <= 3 {
+            true => &self.lengths,
+            false => &[]
+        };
+        f.debug_struct("NVec")
+            .field("lengths", &self.lengths)
+            .field("values", &NVecRows {
+                values: &self.inner,
+                lengths
+            })
+            .finish()
+    }
+}
+// Formats each row of the first axis as its own list
+struct NVecRows<'a, T> {
+    values: &'a [T],
+    lengths: &'a [usize],
+}
+impl<'a, T: std::fmt::Debug> std::fmt::Debug for NVecRows<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.lengths.len() <= 1 {
+            return f.debug_list().entries(self.values.iter()).finish()
+        }
+        let size: usize = self.lengths[1..].iter().product();
+        f.debug_list().entries((0..self.lengths[0]).map(|row| NVecRows {
+            values: &self.values[row*size..(row+1)*size],
+            lengths: &self.lengths[1..]
+        })).finish()
+    }
+}
+/// Reads the lengths, then the values.
+/// Panics if the number of values doesn't match the lengths.
+impl<T: FromBinary, const N: usize> FromBinary for NVec<T, N> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        let mut lengths: [usize; N] = [0; N];
+        for length in lengths.iter_mut() {
+            *length = usize::from_binary(binary);
+        }
+        match NVec::from_vec(Vec::from_binary(binary), lengths) {
+            Ok(nvec) => return nvec,
+            Err(error) => panic!("{error}")
+        }
+    }
+}
+/// Writes the lengths, then the values.
+impl<T: ToBinary, const N: usize> ToBinary for NVec<T, N> {
+    fn to_binary(self, write: &mut dyn Write) {
+        for length in self.lengths {
+            length.to_binary(write);
+        }
+        self.inner.to_binary(write);
+    }
+}
+impl<T, const N: usize> IntoIterator for NVec<T, N> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a NVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a mut NVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+/// [Iter](Iterator) struct for [NVec::indexed_iter]
+pub struct IndexedIter<'a, T, const N: usize> {
+    inner: std::iter::Enumerate<std::slice::Iter<'a, T>>,
+    lengths: [usize; N],
+}
+impl<'a, T, const N: usize> IndexedIter<'a, T, N> {
+    // Undoes NVec::get_index
+    fn get_indexes(&self, mut index: usize) -> [usize; N] {
+        let mut indexes: [usize; N] = [0; N];
+        for axis in (0..N).rev() {
+            indexes[axis] = index % self.lengths[axis];
+            index /= self.lengths[axis];
+        }
+        return indexes
+    }
+}
+impl<'a, T, const N: usize> Iterator for IndexedIter<'a, T, N> {
+    type Item = ([usize; N], &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, value) = self.inner.next()?;
+        Some((self.get_indexes(index), value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T, const N: usize> DoubleEndedIterator for IndexedIter<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (index, value) = self.inner.next_back()?;
+        Some((self.get_indexes(index), value))
+    }
+}
+impl<'a, T, const N: usize> ExactSizeIterator for IndexedIter<'a, T, N> {}
+impl<'a, T, const N: usize> std::iter::FusedIterator for IndexedIter<'a, T, N> {}
+// The part of an NVec where one axis is fixed to an index.
+// This only holds the shape so that it can be shared
+// between the immutable and mutable views.
+// Everything is worked out when the view is made so
+// that indexing doesn't need to allocate.
+struct Slab {
+    // Lengths of the view, which are the parent's without the fixed axis
+    lengths: Vec<usize>,
+    // How far apart values are in the parent along each axis of the view
+    strides: Vec<usize>,
+    // Index in the parent of the first value
+    offset: usize,
+    len: usize,
+}
+impl Slab {
+    fn new(parent_lengths: &[usize], axis: usize, index: usize) -> Self {
+        let mut lengths: Vec<usize> = Vec::with_capacity(parent_lengths.len()-1);
+        let mut strides: Vec<usize> = Vec::with_capacity(parent_lengths.len()-1);
+        let mut offset: usize = 0;
+        let mut stride: usize = 1;
+        for parent_axis in (0..parent_lengths.len()).rev() {
+            match parent_axis == axis {
+                true => offset = index*stride,
+                false => {
+                    lengths.push(parent_lengths[parent_axis]);
+                    strides.push(stride);
+                }
+            }
+            stride *= parent_lengths[parent_axis];
+        }
+        lengths.reverse();
+        strides.reverse();
+        Slab {
+            len: lengths.iter().product(),
+            lengths,
+            strides,
+            offset
+        }
+    }
+    // Index into the parent from the indexes into the slab,
+    // assuming that they are in bounds
+    fn get_index(&self, indexes: &[usize]) -> usize {
+        let mut target: usize = self.offset;
+        for axis in 0..self.lengths.len() {
+            target += indexes[axis]*self.strides[axis];
+        }
+        return target
+    }
+    fn get_index_checked(&self, indexes: &[usize]) -> Option<usize> {
+        if indexes.len() != self.lengths.len() {
+            return None
+        }
+        if indexes.iter().zip(self.lengths.iter()).any(|(index, len)| index >= len) {
+            return None
+        }
+        return Some(self.get_index(indexes))
+    }
+    fn get_index_or_panic(&self, indexes: &[usize]) -> usize {
+        assert_eq!(
+            indexes.len(), self.lengths.len(),
+            "Incorrect number of indexes given:\nexpected: {}, got:{}", self.lengths.len(), indexes.len());
+        for axis in 0..self.lengths.len() {
+            if indexes[axis] >= self.lengths[axis] {
+                panic!(
+                    "Index out of bounds on axis {axis}: the len is {} but the index is {}",
+                    self.lengths[axis], indexes[axis]
+                )
+            }
+        }
+        return self.get_index(indexes)
+    }
+    // Index into the parent of the nth value in the slab
+    fn get_nth_index(&self, mut nth: usize) -> usize {
+        let mut target: usize = self.offset;
+        for axis in (0..self.lengths.len()).rev() {
+            target += (nth % self.lengths[axis])*self.strides[axis];
+            nth /= self.lengths[axis];
+        }
+        return target
+    }
+}
+impl<T, const N: usize> NVec<T, N> {
+    fn get_slab(&self, axis: usize, index: usize) -> Option<Slab> {
+        if axis >= N || index >= self.lengths[axis] {
+            return None
+        }
+        Some(Slab::new(&self.lengths, axis, index))
+    }
+    /// Gives a view of every value where the index on
+    /// the given axis is the given index, for example a row
+    /// of a 2 dimensional [NVec] or a plane of a 3 dimensional one.
+    /// The view has one less dimension than the [NVec] and
+    /// gives [None] if the axis or index are out of bounds.
+    ///```
+    /// # use abes_nice_things::NVec;
+    /// let nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+    /// let row = nvec.axis_view(0, 1).unwrap();
+    /// assert_eq!(row.iter().copied().collect::<Vec<usize>>(), [3, 4, 5]);
+    /// let column = nvec.axis_view(1, 2).unwrap();
+    /// assert_eq!(column[&[1]], 5);
+    ///```
+    pub fn axis_view(&self, axis: usize, index: usize) -> Option<NVecView<'_, T>> {
+        Some(NVecView {
+            slab: self.get_slab(axis, index)?,
+            inner: &self.inner
+        })
+    }
+    /// Same as [axis_view](NVec::axis_view) but
+    /// allows the values to be changed.
+    pub fn axis_view_mut(&mut self, axis: usize, index: usize) -> Option<NVecViewMut<'_, T>> {
+        Some(NVecViewMut {
+            slab: self.get_slab(axis, index)?,
+            inner: &mut self.inner
+        })
+    }
+}
+/// A view of an [NVec] with one axis fixed,
+/// made with [NVec::axis_view].
+/// The number of dimensions is only known at runtime,
+/// so indexes are given as slices.
+pub struct NVecView<'a, T> {
+    slab: Slab,
+    inner: &'a [T],
+}
+impl<'a, T> NVecView<'a, T> {
+    /// The lengths of each axis in the view,
+    /// which are those of the [NVec] without the fixed axis.
+    pub fn lengths(&self) -> &[usize] {
+        &self.slab.lengths
+    }
+    /// The total number of values in the view.
+    pub fn len(&self) -> usize {
+        self.slab.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Gets a reference to the value at the given position,
+    /// or [None] if the wrong number of indexes are
+    /// given or any of them are out of bounds.
+    pub fn get(&self, indexes: &[usize]) -> Option<&'a T> {
+        let index: usize = self.slab.get_index_checked(indexes)?;
+        return Some(&self.inner[index])
+    }
+    /// Iterates through the values in the same order as [NVec::iter].
+    pub fn iter(&self) -> ViewIter<'_, 'a, T> {
+        ViewIter {
+            parent: self,
+            index: 0,
+            back_index: self.len()
+        }
+    }
+}
+impl<'a, T> std::ops::Index<&[usize]> for NVecView<'a, T> {
+    type Output = T;
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        &self.inner[
+            self.slab.get_index_or_panic(index)
+        ]
+    }
+}
+impl<'a, 'b, T> IntoIterator for &'b NVecView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = ViewIter<'b, 'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+/// [Iter](Iterator) struct for [NVecView]
+pub struct ViewIter<'b, 'a, T> {
+    parent: &'b NVecView<'a, T>,
+    index: usize,
+    back_index: usize,
+}
+impl<'b, 'a, T> Iterator for ViewIter<'b, 'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.back_index {
+            return None
+        }
+        self.index += 1;
+        Some(&self.parent.inner[self.parent.slab.get_nth_index(self.index-1)])
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+impl<'b, 'a, T> DoubleEndedIterator for ViewIter<'b, 'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.back_index <= self.index {
+            return None
+        }
+        self.back_index -= 1;
+        Some(&self.parent.inner[self.parent.slab.get_nth_index(self.back_index)])
+    }
+}
+impl<'b, 'a, T> ExactSizeIterator for ViewIter<'b, 'a, T> {
+    fn len(&self) -> usize {
+        self.back_index - self.index
+    }
+}
+impl<'b, 'a, T> std::iter::FusedIterator for ViewIter<'b, 'a, T> {}
+/// A mutable view of an [NVec] with one axis fixed,
+/// made with [NVec::axis_view_mut].
+pub struct NVecViewMut<'a, T> {
+    slab: Slab,
+    inner: &'a mut [T],
+}
+impl<'a, T> NVecViewMut<'a, T> {
+    /// The lengths of each axis in the view,
+    /// which are those of the [NVec] without the fixed axis.
+    pub fn lengths(&self) -> &[usize] {
+        &self.slab.lengths
+    }
+    /// The total number of values in the view.
+    pub fn len(&self) -> usize {
+        self.slab.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Gets a reference to the value at the given position,
+    /// or [None] if the wrong number of indexes are
+    /// given or any of them are out of bounds.
+    pub fn get(&self, indexes: &[usize]) -> Option<&T> {
+        let index: usize = self.slab.get_index_checked(indexes)?;
+        return Some(&self.inner[index])
+    }
+    /// Gets a mutable reference to the value at the given position,
+    /// or [None] if the wrong number of indexes are
+    /// given or any of them are out of bounds.
+    pub fn get_mut(&mut self, indexes: &[usize]) -> Option<&mut T> {
+        let index: usize = self.slab.get_index_checked(indexes)?;
+        return Some(&mut self.inner[index])
+    }
+    /// Iterates through the values in the same order as [NVec::iter].
+    pub fn iter_mut(&mut self) -> ViewIterMut<'_, T> {
+        ViewIterMut {
+            slab: &self.slab,
+            rest: &mut self.inner,
+            start: 0,
+            index: 0,
+            len: self.slab.len
+        }
+    }
+}
+impl<'a, T> std::ops::Index<&[usize]> for NVecViewMut<'a, T> {
+    type Output = T;
+    fn index(&self, index: &[usize]) -> &Self::Output {
+        &self.inner[
+            self.slab.get_index_or_panic(index)
+        ]
+    }
+}
+impl<'a, T> std::ops::IndexMut<&[usize]> for NVecViewMut<'a, T> {
+    fn index_mut(&mut self, index: &[usize]) -> &mut Self::Output {
+        let true_index: usize = self.slab.get_index_or_panic(index);
+        &mut self.inner[true_index]
+    }
+}
+/// [Iter](Iterator) struct for [NVecViewMut]
+pub struct ViewIterMut<'a, T> {
+    slab: &'a Slab,
+    // Everything after the last value given
+    rest: &'a mut [T],
+    // Index in the parent of the start of rest
+    start: usize,
+    index: usize,
+    len: usize,
+}
+impl<'a, T> Iterator for ViewIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None
+        }
+        // The values are always further along in the parent than the
+        // previous ones, so the slice can be split to give out each one
+        let target: usize = self.slab.get_nth_index(self.index);
+        let rest = std::mem::take(&mut self.rest);
+        let (value, rest) = rest[target-self.start..].split_first_mut()?;
+        self.rest = rest;
+        self.start = target+1;
+        self.index += 1;
+        Some(value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len-self.index, Some(self.len-self.index))
+    }
+}
+impl<'a, T> ExactSizeIterator for ViewIterMut<'a, T> {}
+impl<'a, T> std::iter::FusedIterator for ViewIterMut<'a, T> {}
 impl<T, const N: usize> Default for NVec<T, N> {
     fn default() -> Self {
         Self::new()
@@ -330,13 +1051,13 @@ impl<T, const N: usize> std::ops::Index<[usize; N]> for NVec<T, N> {
     type Output = T;
     fn index(&self, index: [usize; N]) -> &Self::Output {
         &self.inner[
-            self.get_index(&index)
+            self.get_index_or_panic(&index)
         ]
     }
 }
 impl<T, const N: usize> std::ops::IndexMut<[usize; N]> for NVec<T, N> {
     fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
-        let true_index: usize = self.get_index(&index);
+        let true_index: usize = self.get_index_or_panic(&index);
         &mut self.inner[true_index]
     }
 }
@@ -344,13 +1065,13 @@ impl<T, const N: usize> std::ops::Index<&[usize]> for NVec<T, N> {
     type Output = T;
     fn index(&self, index: &[usize]) -> &Self::Output {
         &self.inner[
-            self.get_index(&index)
+            self.get_index_or_panic(&index)
         ]
     }
 }
 impl<T, const N: usize> std::ops::IndexMut<&[usize]> for NVec<T, N> {
     fn index_mut(&mut self, index: &[usize]) -> &mut Self::Output {
-        let true_index: usize = self.get_index(&index);
+        let true_index: usize = self.get_index_or_panic(&index);
         &mut self.inner[true_index]
     }
 }
@@ -575,6 +1296,69 @@ impl<T, U> Either<T, U> {
     pub fn new_u(u: U) -> Self {
         Either::U(u)
     }
+    pub fn is_t(&self) -> bool {
+        matches!(self, Either::T(_))
+    }
+    pub fn is_u(&self) -> bool {
+        matches!(self, Either::U(_))
+    }
+    /// Gets the T value, panicking if it is a U
+    pub fn unwrap_t(self) -> T {
+        if let Either::T(t) = self {
+            return t
+        }
+        panic!("Attempted to unwrap to T on U value")
+    }
+    /// Gets the U value, panicking if it is a T
+    pub fn unwrap_u(self) -> U {
+        if let Either::U(u) = self {
+            return u
+        }
+        panic!("Attempted to unwrap to U on T value")
+    }
+    /// Converts the T value using the given method,
+    /// leaving a U value unchanged.
+    pub fn map_t<V>(self, f: impl FnOnce(T) -> V) -> Either<V, U> {
+        match self {
+            Either::T(t) => Either::T(f(t)),
+            Either::U(u) => Either::U(u)
+        }
+    }
+    /// Converts the U value using the given method,
+    /// leaving a T value unchanged.
+    pub fn map_u<V>(self, f: impl FnOnce(U) -> V) -> Either<T, V> {
+        match self {
+            Either::T(t) => Either::T(t),
+            Either::U(u) => Either::U(f(u))
+        }
+    }
+    /// Converts whichever value it has with the matching method.
+    pub fn map<V, W>(self, f_t: impl FnOnce(T) -> V, f_u: impl FnOnce(U) -> W) -> Either<V, W> {
+        match self {
+            Either::T(t) => Either::T(f_t(t)),
+            Either::U(u) => Either::U(f_u(u))
+        }
+    }
+}
+// Rust can't express T == U as a bound
+// so it needs its own block
+impl<T> Either<T, T> {
+    /// Gets the value regardless of
+    /// which variant it is.
+    pub fn into_inner(self) -> T {
+        match self {
+            Either::T(value) => value,
+            Either::U(value) => value
+        }
+    }
+    /// Gets a reference to the value regardless
+    /// of which variant it is.
+    pub fn as_ref(&self) -> &T {
+        match self {
+            Either::T(value) => value,
+            Either::U(value) => value
+        }
+    }
 }
 
 pub fn gen_check<T>(gen: impl Fn() -> T, check: impl Fn(&T) -> bool) -> T {
@@ -590,6 +1374,35 @@ pub fn unwrap_none<T>(input: &Option<T>, message: &str) {
         panic!("{message}")
     }
 }
+macro_rules! encode_helper {
+    ($type: ty, $bits: literal, $encode: ident, $decode: ident) => {
+        #[doc = concat!("Packs ", $bits, " bools into a [", stringify!($type), "]")]
+        /// where the bool at index i is bit i,
+        /// meaning that index 0 is the least significant bit.
+        pub fn $encode(data: [bool; $bits]) -> $type {
+            let mut out: $type = 0;
+            for (index, bit) in data.into_iter().enumerate() {
+                if bit {
+                    out |= 1 << index;
+                }
+            }
+            out
+        }
+        #[doc = concat!("Unpacks a [", stringify!($type), "] into ", $bits, " bools.")]
+        #[doc = concat!("The inverse of [", stringify!($encode), "].")]
+        pub fn $decode(data: $type) -> [bool; $bits] {
+            let mut out = [false; $bits];
+            for (index, bit) in out.iter_mut().enumerate() {
+                *bit = data & (1 << index) != 0;
+            }
+            out
+        }
+    };
+}
+encode_helper!(u8, 8, u8_encode, u8_decode);
+encode_helper!(u16, 16, u16_encode, u16_decode);
+encode_helper!(u32, 32, u32_encode, u32_decode);
+encode_helper!(u64, 64, u64_encode, u64_decode);
 /// Gets input from the terminal
 /// and returns it as a [String]
 pub fn input() -> String {
@@ -646,6 +1459,22 @@ pub fn input_yn(msg: &str) -> bool {
     }
 }
 pub mod error {
+    /// The error for when the number of values
+    /// doesn't match the lengths of an [NVec](super::NVec).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ShapeError {
+        pub expected: usize,
+        pub found: usize,
+    }
+    impl std::fmt::Display for ShapeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Expected {} values for the lengths but found: {}", self.expected, self.found)
+        }
+    }
+    impl std::error::Error for ShapeError {}
+    /// The error for when a lock is already held.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TryLockError;
     #[derive(Debug)]
     pub enum TransError<T> {
         Send(super::SendError<T>),
@@ -664,6 +1493,254 @@ pub mod error {
 }
 #[cfg(test)]
 mod tests {
+    mod once_lock_method {
+        use super::super::OnceLockMethod;
+        #[test]
+        fn try_init() {
+            let lock = OnceLockMethod::new(&|| {5});
+            assert!(lock.try_init(), "Failed to initialize");
+            assert!(!lock.try_init(), "Initialized twice");
+            assert_eq!(*lock.get(), Some(5));
+        }
+        #[test]
+        fn reset() {
+            let lock = OnceLockMethod::new(&|| {"snake"});
+            lock.init();
+            lock.reset();
+            assert!(lock.is_uninit(), "Data remained after reset");
+            assert!(lock.try_init(), "Failed to initialize after reset");
+            assert_eq!(*lock.get(), Some("snake"));
+        }
+    }
+    mod encode {
+        use super::super::*;
+        #[test]
+        fn u8() {
+            let data = [true, false, true, false, false, false, false, true];
+            assert_eq!(u8_encode(data), 0b1000_0101);
+            assert_eq!(u8_decode(0b1000_0101), data);
+        }
+        #[test]
+        fn u16() {
+            let mut data = [false; 16];
+            data[0] = true;
+            data[15] = true;
+            assert_eq!(u16_encode(data), 0b1000_0000_0000_0001);
+            assert_eq!(u16_decode(u16_encode(data)), data);
+        }
+        #[test]
+        fn u32() {
+            let data = u32_decode(0xDEAD_BEEF);
+            assert_eq!(u32_encode(data), 0xDEAD_BEEF);
+        }
+        #[test]
+        fn u64() {
+            assert_eq!(u64_encode([true; 64]), u64::MAX);
+            assert_eq!(u64_decode(0), [false; 64]);
+            let data = u64_decode(0x0123_4567_89AB_CDEF);
+            assert_eq!(u64_encode(data), 0x0123_4567_89AB_CDEF);
+        }
+    }
+    mod nvec {
+        use super::super::*;
+        // Each value is its position as digits: [x, y, z] holds x*100 + y*10 + z
+        fn coordinates(lengths: [usize; 3]) -> NVec<usize, 3> {
+            let mut nvec: NVec<usize, 3> = NVec::from_elem(0, lengths);
+            for x in 0..lengths[0] {
+                for y in 0..lengths[1] {
+                    for z in 0..lengths[2] {
+                        nvec[[x, y, z]] = x*100 + y*10 + z;
+                    }
+                }
+            }
+            nvec
+        }
+        #[test]
+        fn from_vec() {
+            let nvec: NVec<usize, 3> = NVec::from_vec((0..24).collect(), [3, 4, 2]).unwrap();
+            assert_eq!(nvec.lengths(), [3, 4, 2]);
+            assert_eq!(nvec.len(), 24);
+            let mut expected: usize = 0;
+            for x in 0..3 {
+                for y in 0..4 {
+                    for z in 0..2 {
+                        assert_eq!(nvec[[x, y, z]], expected, "Wrong value at: [{x}, {y}, {z}]");
+                        expected += 1;
+                    }
+                }
+            }
+        }
+        #[test]
+        fn from_vec_error() {
+            let result: Result<NVec<usize, 3>, _> = NVec::from_vec((0..23).collect(), [3, 4, 2]);
+            let error = result.err().expect("Accepted wrong number of values");
+            assert_eq!(error, error::ShapeError { expected: 24, found: 23 });
+        }
+        #[test]
+        fn get_out_of_bounds() {
+            let mut nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            assert_eq!(nvec.get(&[1, 2]), Some(&5));
+            // Would be the first value of the next row without the check
+            assert_eq!(nvec.get(&[0, 3]), None);
+            assert_eq!(nvec.get_mut(&[2, 0]), None);
+            *nvec.get_mut(&[1, 0]).unwrap() = 10;
+            assert_eq!(nvec[[1, 0]], 10);
+        }
+        #[test]
+        #[should_panic(expected = "Index out of bounds on axis 1: the len is 3 but the index is 3")]
+        fn index_out_of_bounds() {
+            let nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            let _ = nvec[[0, 3]];
+        }
+        #[test]
+        fn iter() {
+            let mut nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            assert_eq!(nvec.iter().copied().collect::<Vec<usize>>(), [0, 1, 2, 3, 4, 5]);
+            assert_eq!(nvec.iter().rev().next(), Some(&5));
+            for value in nvec.iter_mut() {
+                *value *= 10;
+            }
+            assert_eq!(nvec.get(&[1, 1]), Some(&40));
+            assert_eq!(nvec.into_iter().collect::<Vec<usize>>(), [0, 10, 20, 30, 40, 50]);
+        }
+        #[test]
+        fn indexed_iter() {
+            let nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            let iter = nvec.indexed_iter();
+            assert_eq!(iter.len(), 6);
+            assert_eq!(
+                iter.map(|(indexes, value)| (indexes, *value)).collect::<Vec<([usize; 2], usize)>>(),
+                [([0, 0], 0), ([0, 1], 1), ([0, 2], 2), ([1, 0], 3), ([1, 1], 4), ([1, 2], 5)]
+            );
+            for (indexes, value) in nvec.indexed_iter().rev() {
+                assert_eq!(nvec.get(&indexes), Some(value));
+            }
+        }
+        #[test]
+        fn axis_view() {
+            let nvec = coordinates([2, 3, 4]);
+            let view = nvec.axis_view(0, 1).unwrap();
+            assert_eq!(view.lengths(), [3, 4]);
+            assert_eq!(
+                view.iter().copied().collect::<Vec<usize>>(),
+                (0..3).flat_map(|y| (0..4).map(move |z| 100 + y*10 + z)).collect::<Vec<usize>>()
+            );
+            let view = nvec.axis_view(1, 2).unwrap();
+            assert_eq!(view.lengths(), [2, 4]);
+            assert_eq!(
+                view.iter().copied().collect::<Vec<usize>>(),
+                (0..2).flat_map(|x| (0..4).map(move |z| x*100 + 20 + z)).collect::<Vec<usize>>()
+            );
+            assert_eq!(view.get(&[1, 3]), Some(&123));
+            assert_eq!(view.get(&[2, 0]), None);
+            assert_eq!(view.get(&[0, 0, 0]), None);
+            let view = nvec.axis_view(2, 3).unwrap();
+            assert_eq!(view.lengths(), [2, 3]);
+            assert_eq!(view.iter().rev().copied().collect::<Vec<usize>>(), [123, 113, 103, 23, 13, 3]);
+            assert_eq!(view[&[1, 1]], 113);
+            assert!(nvec.axis_view(3, 0).is_none());
+            assert!(nvec.axis_view(1, 3).is_none());
+        }
+        #[test]
+        fn axis_view_mut() {
+            let mut nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            let mut column = nvec.axis_view_mut(1, 1).unwrap();
+            for value in column.iter_mut() {
+                *value = 0;
+            }
+            column[&[1]] += 7;
+            *column.get_mut(&[0]).unwrap() += 3;
+            assert_eq!(nvec.into_iter().collect::<Vec<usize>>(), [0, 3, 2, 3, 7, 5]);
+        }
+        #[test]
+        #[should_panic(expected = "Index out of bounds on axis 0: the len is 2 but the index is 2")]
+        fn axis_view_out_of_bounds() {
+            let nvec: NVec<usize, 2> = NVec::from_vec((0..6).collect(), [2, 3]).unwrap();
+            let _ = nvec.axis_view(1, 0).unwrap()[&[2]];
+        }
+        #[test]
+        fn debug() {
+            let nvec: NVec<usize, 2> = NVec::from_vec(vec![1, 2, 3, 4], [2, 2]).unwrap();
+            assert_eq!(format!("{nvec:?}"), "NVec { lengths: [2, 2], values: [[1, 2], [3, 4]] }");
+            let nvec: NVec<usize, 4> = NVec::from_elem(0, [1, 1, 1, 2]);
+            assert_eq!(format!("{nvec:?}"), "NVec { lengths: [1, 1, 1, 2], values: [0, 0] }");
+        }
+        #[test]
+        fn binary() {
+            let nvec: NVec<u16, 3> = NVec::from_vec((0..24).collect(), [3, 4, 2]).unwrap();
+            let mut buf: Vec<u8> = Vec::new();
+            nvec.clone().to_binary(&mut buf);
+            assert_eq!(NVec::from_binary(&mut buf.as_slice()), nvec);
+        }
+        #[test]
+        #[should_panic(expected = "Expected 6 values for the lengths but found: 5")]
+        fn binary_mismatch() {
+            let mut buf: Vec<u8> = Vec::new();
+            2_usize.to_binary(&mut buf);
+            3_usize.to_binary(&mut buf);
+            vec![0_u8; 5].to_binary(&mut buf);
+            NVec::<u8, 2>::from_binary(&mut buf.as_slice());
+        }
+        #[test]
+        fn resize_axis() {
+            let mut nvec: NVec<usize, 2> = NVec::from_vec(vec![1, 2, 3, 4], [2, 2]).unwrap();
+            nvec.resize_axis(1, 4, 0);
+            assert_eq!(nvec.lengths(), [2, 4]);
+            assert_eq!(nvec.to_vec(), &vec![1, 2, 0, 0, 3, 4, 0, 0]);
+            nvec.resize_axis(0, 3, 9);
+            assert_eq!(nvec.lengths(), [3, 4]);
+            assert_eq!(nvec.to_vec(), &vec![1, 2, 0, 0, 3, 4, 0, 0, 9, 9, 9, 9]);
+            nvec.resize_axis(1, 1, 0);
+            assert_eq!(nvec.to_vec(), &vec![1, 3, 9]);
+        }
+        #[test]
+        fn truncate_axis() {
+            let mut nvec = coordinates([3, 3, 3]);
+            nvec.truncate_axis(1, 2);
+            assert_eq!(nvec.lengths(), [3, 2, 3]);
+            assert_eq!(nvec.len(), 18);
+            for (indexes, value) in nvec.indexed_iter() {
+                assert_eq!(*value, indexes[0]*100 + indexes[1]*10 + indexes[2], "Moved value at: {indexes:?}");
+            }
+            nvec.truncate_axis(1, 5);
+            assert_eq!(nvec.lengths(), [3, 2, 3]);
+        }
+        #[test]
+        fn neighbors() {
+            let nvec: NVec<usize, 2> = NVec::from_vec((0..9).collect(), [3, 3]).unwrap();
+            assert_eq!(nvec.neighbors([0, 0]).collect::<Vec<_>>(), [([1, 0], &3), ([0, 1], &1)]);
+            assert_eq!(nvec.neighbors([0, 1]).count(), 3);
+            let mut center: Vec<usize> = nvec.neighbors([1, 1]).map(|(_, value)| *value).collect();
+            center.sort();
+            assert_eq!(center, [1, 3, 5, 7]);
+        }
+        #[test]
+        fn neighbors_moore() {
+            let nvec: NVec<usize, 2> = NVec::from_vec((0..9).collect(), [3, 3]).unwrap();
+            assert_eq!(
+                nvec.neighbors_moore([2, 2]).collect::<Vec<_>>(),
+                [([1, 1], &4), ([1, 2], &5), ([2, 1], &7)]
+            );
+            assert_eq!(nvec.neighbors_moore([1, 0]).count(), 5);
+            assert_eq!(
+                nvec.neighbors_moore([1, 1]).map(|(_, value)| *value).collect::<Vec<usize>>(),
+                [0, 1, 2, 3, 5, 6, 7, 8]
+            );
+            for (indexes, value) in nvec.neighbors_moore([0, 2]) {
+                assert_eq!(nvec[indexes], *value);
+            }
+        }
+        #[test]
+        fn from_elem() {
+            let mut nvec: NVec<&str, 2> = NVec::from_elem("a", [2, 3]);
+            assert_eq!(nvec.len(), 6);
+            assert_eq!(nvec[[1, 2]], "a");
+            nvec[[0, 1]] = "b";
+            assert_eq!(nvec[[0, 1]], "b");
+            nvec.fill("c");
+            assert!(nvec.to_vec().iter().all(|value| *value == "c"));
+        }
+    }
     mod thread_init {
         use super::super::ThreadInit;
         #[test]
@@ -687,4 +1764,56 @@ mod tests {
             assert_eq!(init.unwrap(), 15);
         }
     }
+    mod either {
+        use super::super::Either;
+        #[test]
+        fn is() {
+            let t: Either<usize, &str> = Either::new_t(5);
+            let u: Either<usize, &str> = Either::new_u("five");
+            assert!(t.is_t() && !t.is_u());
+            assert!(u.is_u() && !u.is_t());
+        }
+        #[test]
+        fn map_t() {
+            let t: Either<usize, &str> = Either::new_t(5);
+            assert_eq!(t.map_t(|t| t*2).unwrap_t(), 10);
+            let u: Either<usize, &str> = Either::new_u("ten");
+            assert_eq!(u.map_t(|t| t*2).unwrap_u(), "ten");
+        }
+        #[test]
+        fn map_u() {
+            let u: Either<usize, &str> = Either::new_u("ten");
+            assert_eq!(u.map_u(|u| u.len()).unwrap_u(), 3);
+            let t: Either<usize, &str> = Either::new_t(5);
+            assert_eq!(t.map_u(|u| u.len()).unwrap_t(), 5);
+        }
+        #[test]
+        fn map() {
+            let t: Either<usize, &str> = Either::new_t(5);
+            assert_eq!(t.map(|t| t+1, |u| u.len()).unwrap_t(), 6);
+            let u: Either<usize, &str> = Either::new_u("ten");
+            assert_eq!(u.map(|t| t+1, |u| u.len()).unwrap_u(), 3);
+        }
+        #[test]
+        #[should_panic(expected = "Attempted to unwrap to T on U value")]
+        fn unwrap_t_fail() {
+            let u: Either<usize, &str> = Either::new_u("ten");
+            u.unwrap_t();
+        }
+        #[test]
+        #[should_panic(expected = "Attempted to unwrap to U on T value")]
+        fn unwrap_u_fail() {
+            let t: Either<usize, &str> = Either::new_t(5);
+            t.unwrap_u();
+        }
+        #[test]
+        fn into_inner() {
+            let t: Either<&str, &str> = Either::new_t("left");
+            let u: Either<&str, &str> = Either::new_u("right");
+            assert_eq!(*t.as_ref(), "left");
+            assert_eq!(*u.as_ref(), "right");
+            assert_eq!(t.into_inner(), "left");
+            assert_eq!(u.into_inner(), "right");
+        }
+    }
 }
\ No newline at end of file
@@ -2,6 +2,13 @@ use std::cell::UnsafeCell;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::Thread;
 use std::collections::VecDeque;
+use std::sync::{Arc, LockResult, PoisonError, TryLockResult};
+use std::time::{Duration, Instant};
+use std::io::{Read, Write};
+use std::ops::{Deref, DerefMut};
+use std::fmt::Debug;
+use serde::{Serialize, Serializer, Deserialize, Deserializer, ser::SerializeSeq};
+use crate::{FromBinary, ToBinary, error::TryLockError};
 /// Lower level version of [Mutex](std::sync::Mutex)
 /// which is just an [AtomicBool] and your value.
 #[derive(Debug)]
@@ -16,7 +23,37 @@ impl<T> Atomex<T> {
             data: UnsafeCell::new(data),
         }
     }
-    pub fn try_lock(&self) -> Result<&mut T, ()> {
+    /// Attempts to get the lock, giving an error
+    /// if something else is holding it.
+    /// The lock is released when the guard is dropped.
+    pub fn try_lock(&self) -> Result<AtomexGuard<'_, T>, TryLockError> {
+        match self.try_lock_raw() {
+            Ok(data) => {
+                return Ok(AtomexGuard {
+                    inner: data,
+                    parent: self
+                })
+            }
+            Err(_) => {
+                return Err(TryLockError)
+            }
+        }
+    }
+    /// Gets the lock, spinning until it is free.
+    /// Because this spins instead of parking the thread,
+    /// it should only be used when the lock is never held for long.
+    pub fn lock(&self) -> AtomexGuard<'_, T> {
+        loop {
+            if let Ok(guard) = self.try_lock() {
+                return guard
+            }
+            std::hint::spin_loop()
+        }
+    }
+    /// The same as [try_lock](Atomex::try_lock) except that
+    /// it gives the value directly instead of a guard,
+    /// meaning that you have to [unlock](Atomex::unlock) it yourself.
+    pub fn try_lock_raw(&self) -> Result<&mut T, ()> {
         match self.atomic.compare_exchange(
             false,
             true,
@@ -37,10 +74,64 @@ impl<T> Atomex<T> {
     pub fn check_lock(&self) -> bool {
         self.atomic.load(Ordering::SeqCst)
     }
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+    /// Gets the value without locking.
+    /// Having a mutable reference means that
+    /// nothing else can be holding the lock.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
 }
+// Same reasoning as Mutex, only one thread
+// can access the value at a time
+unsafe impl<T: Send> Sync for Atomex<T> {}
+/// Gives access to the value in an [Atomex]
+/// and releases the lock when dropped.
 #[derive(Debug)]
+pub struct AtomexGuard<'a, T> {
+    inner: &'a mut T,
+    parent: &'a Atomex<T>,
+}
+impl<'a, T> Drop for AtomexGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { self.parent.unlock() }
+    }
+}
+impl<'a, T> Deref for AtomexGuard<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+impl<'a, T> DerefMut for AtomexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
+/// A thread waiting in a [Mutec] queue.
+/// The flag gets set to false when either the waiter
+/// gives up or it gets woken, so stale entries
+/// from threads that gave up can be skipped.
+#[derive(Debug)]
+struct Waiter {
+    thread: Thread,
+    waiting: Arc<AtomicBool>,
+}
+impl Waiter {
+    fn new(waiting: Arc<AtomicBool>) -> Self {
+        Waiter {
+            thread: std::thread::current(),
+            waiting
+        }
+    }
+}
+/// The value, the queue of threads waiting for it,
+/// and whether or not it has been poisoned.
+type Slot<T> = (Atomex<UnsafeCell<T>>, Atomex<VecDeque<Waiter>>, AtomicBool);
 pub struct Mutec<T> {
-    inner: Vec<(Atomex<UnsafeCell<T>>, Atomex<VecDeque<Thread>>)>,
+    inner: Vec<Slot<T>>,
 }
 // Block for methods relating to the lock/unlock itself
 impl<T> Mutec<T> {
@@ -49,29 +140,18 @@ impl<T> Mutec<T> {
     }
     pub fn lock(&self, index: usize) -> MutecGuard<T> {
         loop {
-            match self.inner[index].0.try_lock() {
-                Ok(data) => {
-                    // Lock is free
-                    return MutecGuard {
-                        inner: unsafe { data.get().as_mut() }.unwrap(),
-                        parent: self,
-                        index
-                    }
-                }
-                Err(_) => {
-                    // Lock is not free
-                    if let Ok(data) = self.inner[index].1.try_lock() {
-                        // Can modify list
-                        data.push_back(std::thread::current());
-                        unsafe { self.inner[index].1.unlock() }
-                        std::thread::park();
-                    }
-                }
+            if let Ok(guard) = self.try_lock(index) {
+                // Lock is free
+                return guard
+            }
+            // Lock is not free
+            if let Some(guard) = self.wait(index, None) {
+                return guard
             }
         }
     }
     pub fn try_lock(&self, index: usize) -> Result<MutecGuard<T>, ()> {
-        match self.inner[index].0.try_lock() {
+        match self.inner[index].0.try_lock_raw() {
             Ok(data) => {
                 return Ok(MutecGuard {
                     inner: unsafe { data.get().as_mut() }.unwrap(),
@@ -84,11 +164,93 @@ impl<T> Mutec<T> {
             }
         }
     }
+    /// Same as [lock](Mutec::lock) except that it gives an error
+    /// if a thread panicked while holding the lock.
+    /// The error still contains the guard in case
+    /// you want to use the value anyway.
+    pub fn lock_checked(&self, index: usize) -> LockResult<MutecGuard<T>> {
+        let guard = self.lock(index);
+        if self.is_poisoned(index) {
+            return Err(PoisonError::new(guard))
+        }
+        Ok(guard)
+    }
+    /// Same as [try_lock](Mutec::try_lock) except that it gives an error
+    /// if a thread panicked while holding the lock.
+    pub fn try_lock_checked(&self, index: usize) -> TryLockResult<MutecGuard<T>> {
+        match self.try_lock(index) {
+            Ok(guard) => {
+                if self.is_poisoned(index) {
+                    return Err(std::sync::TryLockError::Poisoned(PoisonError::new(guard)))
+                }
+                return Ok(guard)
+            }
+            Err(_) => return Err(std::sync::TryLockError::WouldBlock)
+        }
+    }
+    /// Whether or not a thread panicked
+    /// while holding the lock for the index.
+    pub fn is_poisoned(&self, index: usize) -> bool {
+        self.inner[index].2.load(Ordering::SeqCst)
+    }
+    /// Marks the index as no longer poisoned,
+    /// presumably after you fixed whatever the
+    /// panicking thread left behind.
+    pub fn clear_poison(&self, index: usize) {
+        self.inner[index].2.store(false, Ordering::SeqCst)
+    }
+    /// Attempts to get the lock until the timeout has passed.
+    /// This is a middle ground between [lock](Mutec::lock),
+    /// which will wait for however long it takes, and
+    /// [try_lock](Mutec::try_lock), which gives up immediately.
+    pub fn try_lock_for(&self, index: usize, timeout: Duration) -> Option<MutecGuard<T>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Ok(guard) = self.try_lock(index) {
+                return Some(guard)
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None
+            }
+            if let Some(guard) = self.wait(index, Some(deadline - now)) {
+                return Some(guard)
+            }
+        }
+    }
+    /// Adds the current thread to the queue for the index then parks
+    /// until it gets woken by an unlock or the timeout passes.
+    /// Gives the guard if the lock was released before
+    /// the thread made it into the queue.
+    fn wait(&self, index: usize, timeout: Option<Duration>) -> Option<MutecGuard<T>> {
+        let waiting = Arc::new(AtomicBool::new(true));
+        self.inner[index].1.lock().push_back(Waiter::new(waiting.clone()));
+        // If the lock was released after we failed to get it
+        // but before we were in the queue, then nothing
+        // would ever wake us, so we need to check again
+        if let Ok(guard) = self.try_lock(index) {
+            waiting.store(false, Ordering::SeqCst);
+            return Some(guard)
+        }
+        match timeout {
+            Some(timeout) => std::thread::park_timeout(timeout),
+            None => std::thread::park()
+        }
+        // In case we timed out or woke spuriously,
+        // our entry is now stale and needs to be skipped
+        waiting.store(false, Ordering::SeqCst);
+        None
+    }
     unsafe fn unlock(&self, index: usize) {
         self.inner[index].0.unlock();
-        if let Ok(data) = self.inner[index].1.try_lock() {
-            if let Some(thread) = data.pop_front() {
-                thread.unpark()
+        // The queue is only ever held long enough
+        // to push or pop, so spinning is fine
+        let mut queue = self.inner[index].1.lock();
+        while let Some(waiter) = queue.pop_front() {
+            // Skipping anything that gave up waiting
+            if waiter.waiting.swap(false, Ordering::SeqCst) {
+                waiter.thread.unpark();
+                break
             }
         }
     }
@@ -100,6 +262,36 @@ impl<T> Mutec<T> {
             inner: Vec::new()
         }
     }
+    /// Creates an empty [Mutec] with space
+    /// for at least the given number of values.
+    pub fn with_capacity(capacity: usize) -> Mutec<T> {
+        Mutec {
+            inner: Vec::with_capacity(capacity)
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+    /// Reserves space for at least the given
+    /// number of additional values.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    /// Whether or not any value is equal to the given one.
+    /// This [locks](Mutec::lock) each value in order while checking it,
+    /// so if another thread is holding a lock, this will
+    /// wait for it to be released rather than skipping it.
+    pub fn contains(&self, value: &T) -> bool where T: PartialEq {
+        for index in 0..self.len() {
+            if *self.lock(index) == *value {
+                return true
+            }
+        }
+        false
+    }
     pub fn push(&mut self, value: T) {
         self.inner.push((
             Atomex::new(
@@ -108,11 +300,81 @@ impl<T> Mutec<T> {
             Atomex::new(
                 VecDeque::new()
             ),
+            AtomicBool::new(false),
         ))
     }
     pub fn len(&self) -> usize {
         self.inner.len()
     }
+    /// Gets a mutable reference to the value at the index
+    /// without locking it, or None if it is out of bounds.
+    /// This is fine because a mutable reference to the [Mutec]
+    /// means that there can't be any [MutecGuards](MutecGuard)
+    /// for it, so no locks can be held.
+    /// As such, you can't hold a [MutecGuard] while doing this:
+    ///```compile_fail
+    /// # use abes_nice_things::mutec::Mutec;
+    /// let mut mutec = Mutec::from([1, 2]);
+    /// let guard = mutec.lock(0);
+    /// *mutec.get_mut(1).unwrap() = 5;
+    /// drop(guard);
+    ///```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.inner.get_mut(index).map(|(data, _, _)| data.get_mut().get_mut())
+    }
+    /// Iterates through mutable references to the values
+    /// without locking them. This is fine for the same reason
+    /// as [get_mut](Mutec::get_mut).
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            inner: self.inner.iter_mut()
+        }
+    }
+    /// Removes the last value and returns it,
+    /// or None if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop().map(Self::slot_into_inner)
+    }
+    /// Removes the value at the index and returns it,
+    /// shifting every value after it one index down.
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        Self::slot_into_inner(self.inner.remove(index))
+    }
+    /// Removes the value at the index and returns it,
+    /// replacing it with the last value.
+    /// Unlike [remove](Mutec::remove), this doesn't shift
+    /// everything after it, but it does change the order.
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        Self::slot_into_inner(self.inner.swap_remove(index))
+    }
+    /// Removes every value at or past the given length,
+    /// doing nothing if it is already shorter than that.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len() > len {
+            self.pop();
+        }
+    }
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+    // Taking ownership of the slot means that there
+    // can't be any guards or threads waiting for it
+    fn slot_into_inner(slot: Slot<T>) -> T {
+        // Only possible if a guard was leaked with std::mem::forget
+        debug_assert!(!slot.0.check_lock(), "Removed value was still locked");
+        debug_assert!(
+            slot.1.into_inner().iter().all(|waiter| !waiter.waiting.load(Ordering::SeqCst)),
+            "Removed value still had threads waiting for it"
+        );
+        slot.0.into_inner().into_inner()
+    }
+    /// Takes all the values out in index order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
     pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), std::collections::TryReserveError> {
         self.inner.try_reserve_exact(additional)
     }
@@ -139,12 +401,39 @@ impl<T> Mutec<T> {
     /// }
     /// ```
     pub fn to_vec(&self) -> Vec<MutecGuard<T>> {
+        self.lock_all()
+    }
+    /// Gets the [locks](Mutec::lock) of every value in index order.
+    pub fn lock_all(&self) -> Vec<MutecGuard<T>> {
         let mut vec: Vec<MutecGuard<T>> = Vec::with_capacity(self.len());
         for index in 0..self.len() {
             vec.push(self.lock(index))
         }
         vec
     }
+    /// Gets the [locks](Mutec::lock) of the values at the given indices.
+    /// The indices are sorted and deduplicated before locking so that
+    /// everything locking multiple values does it in the same order,
+    /// meaning that two threads locking {1,3} and {3,1} can't deadlock.
+    /// Because of that, the guards are given in index order
+    /// rather than the order they were asked for in.
+    /// 
+    /// Panics before locking anything if any index is out of bounds.
+    pub fn lock_many(&self, indices: &[usize]) -> Vec<MutecGuard<T>> {
+        for index in indices.iter() {
+            if *index >= self.len() {
+                panic!("Index out of bounds: the len is {} but the index is {index}", self.len())
+            }
+        }
+        let mut indices: Vec<usize> = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        let mut vec: Vec<MutecGuard<T>> = Vec::with_capacity(indices.len());
+        for index in indices {
+            vec.push(self.lock(index))
+        }
+        vec
+    }
     pub fn iter(&self) -> Iter<T> {
         Iter {
             parent: self,
@@ -152,6 +441,46 @@ impl<T> Mutec<T> {
             back_index: self.inner.len()
         }
     }
+    /// Runs the method on every value using the given number
+    /// of threads, returning once they are all done.
+    /// The threads share an [AsyncIter], so each value is only
+    /// done once, and values locked by something else
+    /// get done after the ones which are free.
+    pub fn par_for_each(&self, threads: usize, f: impl Fn(&mut T) + Sync) where T: Send {
+        self.par_map(threads, |value| f(value));
+    }
+    /// Same as [par_for_each](Mutec::par_for_each) except that
+    /// it gives what the method returned for each value in index order.
+    pub fn par_map<U: Send>(&self, threads: usize, f: impl Fn(&mut T) -> U + Sync) -> Vec<U> where T: Send {
+        let iter = std::sync::Mutex::new(self.async_iter());
+        let mut out: Vec<Option<U>> = Vec::with_capacity(self.len());
+        out.resize_with(self.len(), || None);
+        std::thread::scope(|s| {
+            let mut handles = Vec::with_capacity(threads);
+            for _ in 0..threads.max(1) {
+                handles.push(s.spawn(|| {
+                    let mut done: Vec<(usize, U)> = Vec::new();
+                    loop {
+                        // The iter lock is dropped before running the method
+                        let next = iter.lock().unwrap().next();
+                        match next {
+                            Some(mut guard) => {
+                                let index = guard.index;
+                                done.push((index, f(&mut guard)))
+                            }
+                            None => return done
+                        }
+                    }
+                }))
+            }
+            for handle in handles {
+                for (index, value) in handle.join().unwrap() {
+                    out[index] = Some(value)
+                }
+            }
+        });
+        out.into_iter().map(|value| value.expect("Value was skipped")).collect()
+    }
     pub fn async_iter(&self) -> AsyncIter<T> {
         let mut progress: Vec<bool> = Vec::with_capacity(self.len());
         for _ in 0..self.len() {
@@ -196,15 +525,81 @@ impl<T> FromIterator<T> for Mutec<T> {
 }
 impl<T> Extend<T> for Mutec<T> {
     fn extend<A: IntoIterator<Item = T>>(&mut self, iter: A) {
-        // Not sure if reserving here is faster bc
-        // It would mean having to go through the iter
-        // twice...
-        // TODO: find out
-        for item in iter.into_iter() {
+        // The size hint doesn't need to go through the iter,
+        // so reserving the lower bound is free
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
             self.push(item)
         }
     }
 }
+impl<T> IntoIterator for Mutec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.inner.into_iter()
+        }
+    }
+}
+/// Shows \<locked> for anything which is currently
+/// locked instead of waiting for it.
+impl<T: Debug> Debug for Mutec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        struct Locked;
+        impl Debug for Locked {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "<locked>")
+            }
+        }
+        let mut list = f.debug_list();
+        for index in 0..self.len() {
+            match self.try_lock(index) {
+                Ok(guard) => list.entry(&*guard),
+                Err(_) => list.entry(&Locked)
+            };
+        }
+        list.finish()
+    }
+}
+impl<T: FromBinary> FromBinary for Mutec<T> {
+    fn from_binary(binary: &mut dyn Read) -> Self {
+        Vec::<T>::from_binary(binary).into()
+    }
+}
+impl<T: ToBinary> ToBinary for Mutec<T> {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.into_vec().to_binary(write)
+    }
+}
+/// Writes the same thing as the owned version,
+/// but [locks](Mutec::lock) each value in index order
+/// and writes a clone of it.
+impl<T: ToBinary + Clone> ToBinary for &Mutec<T> {
+    fn to_binary(self, write: &mut dyn Write) {
+        self.len().to_binary(write);
+        for index in 0..self.len() {
+            self.lock(index).clone().to_binary(write)
+        }
+    }
+}
+/// [Locks](Mutec::lock) each value in index order
+/// while serializing it.
+impl<T: Serialize> Serialize for Mutec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for index in 0..self.len() {
+            seq.serialize_element(&*self.lock(index))?;
+        }
+        seq.end()
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Mutec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<T>::deserialize(deserializer)?.into())
+    }
+}
 unsafe impl<T> Sync for Mutec<T> {}
 unsafe impl<T> Send for Mutec<T> {}
 /// [Iter](Iterator) struct for [Mutec]
@@ -241,6 +636,47 @@ impl<'a, T> ExactSizeIterator for Iter<'a, T> {
     }
 }
 impl<'a, T> std::iter::FusedIterator for Iter<'a, T> {}
+/// Owning [Iter](Iterator) struct for [Mutec]
+pub struct IntoIter<T> {
+    inner: std::vec::IntoIter<Slot<T>>,
+}
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Mutec::slot_into_inner)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Mutec::slot_into_inner)
+    }
+}
+impl<T> ExactSizeIterator for IntoIter<T> {}
+impl<T> std::iter::FusedIterator for IntoIter<T> {}
+/// Mutable [Iter](Iterator) struct for [Mutec]
+/// which doesn't lock anything.
+pub struct IterMut<'a, T> {
+    inner: std::slice::IterMut<'a, Slot<T>>,
+}
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(data, _, _)| data.get_mut().get_mut())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(data, _, _)| data.get_mut().get_mut())
+    }
+}
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+impl<'a, T> std::iter::FusedIterator for IterMut<'a, T> {}
 pub struct AsyncIter<'a, T> {
     parent: &'a Mutec<T>,
     progress: Vec<bool>,
@@ -272,12 +708,90 @@ pub struct MutecGuard<'a, T> {
     parent: &'a Mutec<T>,
     index: usize,
 }
+impl<'a, T> MutecGuard<'a, T> {
+    /// Puts the new value in and gives the old one.
+    /// Same as [std::mem::replace].
+    pub fn replace(&mut self, new: T) -> T {
+        std::mem::replace(self.inner, new)
+    }
+    /// Takes the value, leaving the default in its place.
+    /// Same as [std::mem::take].
+    pub fn take(&mut self) -> T where T: Default {
+        std::mem::take(self.inner)
+    }
+    /// Turns this into a guard for something inside the value,
+    /// such as one of its fields, while keeping it locked.
+    ///```
+    /// # use abes_nice_things::mutec::{Mutec, MutecGuard};
+    /// let mutec = Mutec::from([(1, "one")]);
+    /// let mut first = MutecGuard::map(mutec.lock(0), |pair| &mut pair.0);
+    /// *first = 2;
+    /// drop(first);
+    /// assert_eq!(*mutec.lock(0), (2, "one"));
+    ///```
+    pub fn map<U>(guard: Self, f: impl FnOnce(&mut T) -> &mut U) -> MappedMutecGuard<'a, U> {
+        let inner: *mut T = &mut *guard.inner;
+        // The guard is kept alive while running f
+        // so that it still unlocks and poisons if f panics
+        let inner: &'a mut U = f(unsafe { &mut *inner });
+        // The mapped guard is now the one responsible for unlocking
+        let guard = std::mem::ManuallyDrop::new(guard);
+        MappedMutecGuard {
+            inner,
+            parent: guard.parent,
+            index: guard.index
+        }
+    }
+}
 impl<'a, T> Drop for MutecGuard<'a, T> {
     fn drop(&mut self) {
-        unsafe { self.parent.unlock(self.index) }
+        self.parent.release(self.index)
+    }
+}
+// Lets MappedMutecGuard unlock without knowing the type of the Mutec
+trait Release {
+    fn release(&self, index: usize);
+}
+impl<T> Release for Mutec<T> {
+    fn release(&self, index: usize) {
+        if std::thread::panicking() {
+            self.inner[index].2.store(true, Ordering::SeqCst)
+        }
+        unsafe { self.unlock(index) }
+    }
+}
+/// A [MutecGuard] which has been [mapped](MutecGuard::map)
+/// to something inside the locked value.
+/// The value stays locked until this is dropped.
+pub struct MappedMutecGuard<'a, U> {
+    inner: &'a mut U,
+    parent: &'a dyn Release,
+    index: usize,
+}
+impl<'a, U> Drop for MappedMutecGuard<'a, U> {
+    fn drop(&mut self) {
+        self.parent.release(self.index)
+    }
+}
+impl<'a, U: Debug> Debug for MappedMutecGuard<'a, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedMutecGuard")
+            .field("inner", &self.inner)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+impl<'a, U> Deref for MappedMutecGuard<'a, U> {
+    type Target = U;
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+impl<'a, U> DerefMut for MappedMutecGuard<'a, U> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
     }
 }
-use std::ops::{Deref, DerefMut};
 impl<'a, T> Deref for MutecGuard<'a, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -310,17 +824,47 @@ mod tests {
                 *atomex.try_lock().expect("failed to get available lock"),
                 5,
                 "Value was changed in locking"
-            )
+            );
         }
         #[test]
         fn basic_all() {
             let atomex: Atomex<usize> = Atomex::new(7);
             assert!(!atomex.check_lock(),"Atomex was locked on creation");
-            atomex.try_lock().expect("failed to acquire lock");
+            let guard = atomex.try_lock().expect("failed to acquire lock");
             assert!(atomex.check_lock(), "Atomex was unlocked after lock was acquired");
             atomex.try_lock().expect_err("Lock was gained while locked");
+            drop(guard);
+            assert!(!atomex.check_lock(), "Atomex was locked after dropping guard");
+        }
+        #[test]
+        fn raw() {
+            let atomex: Atomex<usize> = Atomex::new(7);
+            *atomex.try_lock_raw().expect("failed to acquire lock") = 8;
+            assert!(atomex.check_lock(), "Atomex was unlocked after lock was acquired");
+            atomex.try_lock_raw().expect_err("Lock was gained while locked");
             unsafe { atomex.unlock() }
             assert!(!atomex.check_lock(), "Atomex was locked after unlocking");
+            assert_eq!(*atomex.try_lock().unwrap(), 8, "Change was not kept");
+        }
+        #[test]
+        fn guard_mut() {
+            let atomex: Atomex<usize> = Atomex::new(7);
+            *atomex.try_lock().expect("failed to acquire lock") = 9;
+            assert_eq!(*atomex.lock(), 9, "Change was not kept");
+        }
+        #[test]
+        fn spin_lock() {
+            let atomex: Atomex<usize> = Atomex::new(0);
+            std::thread::scope(|s| {
+                for _ in 0..8 {
+                    s.spawn(|| {
+                        for _ in 0..1000 {
+                            *atomex.lock() += 1;
+                        }
+                    });
+                }
+            });
+            assert_eq!(*atomex.lock(), 8000);
         }
     }
     mod mutec {
@@ -376,6 +920,303 @@ mod tests {
             });
             assert_eq!(*mutec.lock(0), "today", "BLYAT");
         }
+        #[test]
+        fn wait_after_unlock() {
+            // A thread which failed to get the lock, then had the
+            // lock released before it made it into the queue
+            let mutec: Mutec<usize> = Mutec::from([1]);
+            let guard = mutec.lock(0);
+            mutec.try_lock(0).expect_err("Got lock while it was held");
+            drop(guard);
+            let start = Instant::now();
+            assert!(mutec.wait(0, Some(Duration::from_secs(5))).is_some(), "Missed the unlock");
+            assert!(start.elapsed() < Duration::from_secs(1), "Waited for the timeout");
+        }
+        #[test]
+        fn lock_stress() {
+            let mutec: Mutec<usize> = Mutec::from([0]);
+            std::thread::scope(|s| {
+                for _ in 0..16 {
+                    s.spawn(|| {
+                        for _ in 0..1000 {
+                            *mutec.lock(0) += 1;
+                        }
+                    });
+                }
+            });
+            assert_eq!(*mutec.lock(0), 16000);
+        }
+        #[test]
+        fn poison() {
+            let mutec: Mutec<usize> = Mutec::from([1, 2]);
+            std::thread::scope(|s| {
+                let result = s.spawn(|| {
+                    let mut guard = mutec.lock(0);
+                    *guard = 5;
+                    panic!("Intentional panic while holding guard");
+                }).join();
+                assert!(result.is_err(), "Thread did not panic");
+            });
+            assert!(mutec.is_poisoned(0), "Panic did not poison");
+            assert!(!mutec.is_poisoned(1), "Poisoned the wrong index");
+            match mutec.lock_checked(0) {
+                Ok(_) => panic!("Got checked lock on poisoned index"),
+                Err(error) => assert_eq!(*error.into_inner(), 5, "Value was lost")
+            }
+            assert!(
+                matches!(mutec.try_lock_checked(0), Err(std::sync::TryLockError::Poisoned(_))),
+                "Got checked try lock on poisoned index"
+            );
+            mutec.clear_poison(0);
+            assert!(!mutec.is_poisoned(0), "Poison was not cleared");
+            assert!(mutec.lock_checked(0).is_ok(), "Failed to get checked lock after clearing");
+        }
+        mod binary {
+            use super::super::super::*;
+            #[test]
+            fn round_trip() {
+                let mutec: Mutec<u32> = Mutec::from([5, 2, 7, 42]);
+                let mut buf: Vec<u8> = Vec::new();
+                (&mutec).to_binary(&mut buf);
+                let owned: Mutec<u32> = Mutec::from_binary(&mut buf.as_slice());
+                assert_eq!(owned.into_vec(), [5, 2, 7, 42]);
+                let mut owned_buf: Vec<u8> = Vec::new();
+                mutec.to_binary(&mut owned_buf);
+                assert_eq!(buf, owned_buf, "Owned and borrowed versions were different");
+            }
+            #[test]
+            fn waits_for_lock() {
+                let mutec: Mutec<u32> = Mutec::from([1, 2]);
+                let mut guard = mutec.lock(1);
+                let buf = std::thread::scope(|s| {
+                    let handle = s.spawn(|| {
+                        let mut buf: Vec<u8> = Vec::new();
+                        (&mutec).to_binary(&mut buf);
+                        buf
+                    });
+                    std::thread::sleep(Duration::from_millis(20));
+                    *guard = 3;
+                    drop(guard);
+                    handle.join().unwrap()
+                });
+                assert_eq!(Mutec::<u32>::from_binary(&mut buf.as_slice()).into_vec(), [1, 3]);
+            }
+            #[test]
+            fn serde() {
+                #[derive(Serialize, Deserialize)]
+                struct Config {
+                    values: Mutec<usize>
+                }
+                let config = Config { values: Mutec::from([1, 2, 3]) };
+                let string = toml::to_string(&config).unwrap();
+                let back: Config = toml::from_str(&string).unwrap();
+                assert_eq!(back.values.into_vec(), [1, 2, 3]);
+            }
+            #[test]
+            fn debug() {
+                let mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+                let _guard = mutec.lock(1);
+                assert_eq!(format!("{mutec:?}"), "[1, <locked>, 3]");
+            }
+        }
+        mod guard {
+            use super::super::super::*;
+            #[test]
+            fn replace() {
+                let mutec: Mutec<&str> = Mutec::from(["old"]);
+                assert_eq!(mutec.lock(0).replace("new"), "old");
+                assert_eq!(*mutec.lock(0), "new");
+            }
+            #[test]
+            fn take() {
+                let mutec: Mutec<usize> = Mutec::from([5]);
+                assert_eq!(mutec.lock(0).take(), 5);
+                assert_eq!(*mutec.lock(0), 0);
+            }
+            #[test]
+            fn map() {
+                let mutec: Mutec<(usize, &str)> = Mutec::from([(1, "one")]);
+                let mut mapped = MutecGuard::map(mutec.lock(0), |pair| &mut pair.1);
+                assert_eq!(*mapped, "one");
+                *mapped = "won";
+                assert!(mutec.try_lock(0).is_err(), "Value was unlocked while mapped guard existed");
+                drop(mapped);
+                assert!(!mutec.check_lock(0), "Value was not unlocked when mapped guard was dropped");
+                assert_eq!(*mutec.lock(0), (1, "won"), "Change was not visible");
+            }
+            #[test]
+            fn map_panic() {
+                let mutec: Mutec<(usize, &str)> = Mutec::from([(1, "one")]);
+                std::thread::scope(|s| {
+                    let result = s.spawn(|| {
+                        MutecGuard::map(mutec.lock(0), |_| -> &mut usize {
+                            panic!("Intentional panic while mapping")
+                        });
+                    }).join();
+                    assert!(result.is_err(), "Thread did not panic");
+                });
+                assert!(!mutec.check_lock(0), "Value stayed locked after map panicked");
+                assert!(mutec.is_poisoned(0), "Panic while mapping did not poison");
+            }
+        }
+        #[test]
+        fn lock_many() {
+            let mutec: Mutec<usize> = Mutec::from([0, 1, 2, 3, 4]);
+            let guards = mutec.lock_many(&[3, 1, 3]);
+            assert_eq!(guards.len(), 2, "Duplicate index was not removed");
+            assert_eq!(guards[0], 1, "Guards were not in index order");
+            assert_eq!(guards[1], 3, "Guards were not in index order");
+            assert!(mutec.check_lock(1) && mutec.check_lock(3), "Values were not locked");
+            assert!(!mutec.check_lock(0), "Extra value was locked");
+        }
+        #[test]
+        #[should_panic(expected = "the index is 5")]
+        fn lock_many_out_of_bounds() {
+            let mutec: Mutec<usize> = Mutec::from([0, 1, 2, 3, 4]);
+            mutec.lock_many(&[1, 5]);
+        }
+        #[test]
+        fn lock_many_no_deadlock() {
+            let mutec: Mutec<usize> = Mutec::from([0, 0, 0, 0]);
+            std::thread::scope(|s| {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        for mut guard in mutec.lock_many(&[1, 3]) {
+                            *guard += 1;
+                        }
+                    }
+                });
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        for mut guard in mutec.lock_many(&[3, 1]) {
+                            *guard += 1;
+                        }
+                    }
+                });
+            });
+            assert_eq!(*mutec.lock(1), 2000);
+            assert_eq!(*mutec.lock(3), 2000);
+        }
+        #[test]
+        fn lock_all() {
+            let mutec: Mutec<usize> = Mutec::from([4, 5, 6]);
+            let guards = mutec.lock_all();
+            assert_eq!(guards.len(), 3, "Not every value was locked");
+            for (guard, item) in guards.iter().zip([4, 5, 6]) {
+                assert_eq!(*guard, item, "Guards were not in index order");
+            }
+        }
+        #[test]
+        fn try_lock_for_timeout() {
+            let mutec: Mutec<usize> = Mutec::from([1]);
+            let guard = mutec.lock(0);
+            std::thread::scope(|s| {
+                s.spawn(|| {
+                    let start = Instant::now();
+                    assert!(
+                        mutec.try_lock_for(0, Duration::from_millis(50)).is_none(),
+                        "Got lock while it was held"
+                    );
+                    assert!(start.elapsed() >= Duration::from_millis(50), "Gave up early");
+                });
+            });
+            drop(guard);
+            let start = Instant::now();
+            assert!(mutec.try_lock_for(0, Duration::from_secs(5)).is_some(), "Failed to get free lock");
+            assert!(start.elapsed() < Duration::from_secs(1), "Waited on free lock");
+        }
+        #[test]
+        fn try_lock_for_wait() {
+            let mutec: Mutec<usize> = Mutec::from([1]);
+            let guard = mutec.lock(0);
+            std::thread::scope(|s| {
+                s.spawn(|| {
+                    let mut guard = mutec.try_lock_for(0, Duration::from_secs(5))
+                        .expect("Failed to get lock after it was released");
+                    *guard = 2;
+                });
+                std::thread::sleep(Duration::from_millis(20));
+                drop(guard);
+            });
+            assert_eq!(*mutec.lock(0), 2);
+        }
+        #[test]
+        fn pop() {
+            let mut mutec: Mutec<usize> = Mutec::from([1, 2]);
+            assert_eq!(mutec.pop(), Some(2));
+            assert_eq!(mutec.pop(), Some(1));
+            assert_eq!(mutec.pop(), None);
+        }
+        #[test]
+        fn remove() {
+            let mut mutec: Mutec<usize> = Mutec::from([1, 2, 3, 4]);
+            assert_eq!(mutec.remove(1), 2);
+            assert_eq!(mutec.len(), 3);
+            assert_eq!(*mutec.lock(1), 3, "Later values were not shifted down");
+            assert_eq!(*mutec.lock(2), 4, "Later values were not shifted down");
+        }
+        #[test]
+        fn swap_remove() {
+            let mut mutec: Mutec<usize> = Mutec::from([1, 2, 3, 4]);
+            assert_eq!(mutec.swap_remove(1), 2);
+            assert_eq!(mutec.len(), 3);
+            assert_eq!(*mutec.lock(1), 4, "Last value was not moved into place");
+            assert_eq!(*mutec.lock(2), 3, "Other values were moved");
+        }
+        #[test]
+        fn truncate() {
+            let mut mutec: Mutec<usize> = Mutec::from([1, 2, 3, 4]);
+            mutec.truncate(5);
+            assert_eq!(mutec.len(), 4);
+            mutec.truncate(2);
+            assert_eq!(mutec.len(), 2);
+            assert_eq!(*mutec.lock(1), 2);
+            mutec.clear();
+            assert_eq!(mutec.len(), 0);
+        }
+        #[test]
+        fn capacity() {
+            let mut mutec: Mutec<usize> = Mutec::with_capacity(10);
+            assert!(mutec.capacity() >= 10);
+            assert!(mutec.is_empty());
+            mutec.extend([1, 2, 3]);
+            assert!(!mutec.is_empty());
+            mutec.reserve(20);
+            assert!(mutec.capacity() >= 23);
+        }
+        #[test]
+        fn extend_reserves() {
+            let mut mutec: Mutec<usize> = Mutec::new();
+            mutec.extend(0..100);
+            assert!(mutec.capacity() >= 100);
+            assert_eq!(mutec.len(), 100);
+        }
+        #[test]
+        fn contains() {
+            let mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+            assert!(mutec.contains(&2));
+            assert!(!mutec.contains(&4));
+        }
+        #[test]
+        fn contains_locked() {
+            let mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+            let mut guard = mutec.lock(1);
+            std::thread::scope(|s| {
+                let handle = s.spawn(|| mutec.contains(&5));
+                std::thread::sleep(Duration::from_millis(20));
+                *guard = 5;
+                drop(guard);
+                assert!(handle.join().unwrap(), "Locked value was skipped");
+            });
+        }
+        #[test]
+        fn get_mut() {
+            let mut mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+            *mutec.get_mut(1).expect("Value was missing") = 7;
+            assert!(mutec.get_mut(3).is_none(), "Got value out of bounds");
+            assert_eq!(*mutec.lock(1), 7, "Change was not visible through lock");
+            assert!(!mutec.check_lock(1), "get_mut left the value locked");
+        }
         mod iter {
             use super::super::super::*;
             #[test]
@@ -426,6 +1267,40 @@ mod tests {
                 assert!(mutec_iter.next_back().is_none(), "mutec iter had an extra value");
                 assert!(mutec_iter.next_back().is_none(), "mutec iter had an extra value");
             }
+            #[test]
+            fn into_iter() {
+                let source: Vec<&str> = vec!["5", "6", "7"];
+                let mutec: Mutec<&str> = Mutec::from(source.clone());
+                assert_eq!(mutec.into_iter().collect::<Vec<&str>>(), source);
+            }
+            #[test]
+            fn into_iter_back() {
+                let mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+                assert_eq!(mutec.into_iter().rev().collect::<Vec<usize>>(), [3, 2, 1]);
+            }
+            #[test]
+            fn into_vec() {
+                let source: Vec<usize> = vec![9, 8, 7, 6];
+                assert_eq!(Mutec::from(source.clone()).into_vec(), source);
+            }
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "Removed value was still locked")]
+            fn into_iter_leaked_guard() {
+                let mutec: Mutec<usize> = Mutec::from([1]);
+                std::mem::forget(mutec.lock(0));
+                mutec.into_vec();
+            }
+            #[test]
+            fn iter_mut() {
+                let mut mutec: Mutec<usize> = Mutec::from([1, 2, 3]);
+                for item in mutec.iter_mut() {
+                    *item *= 10;
+                }
+                for (index, item) in [10, 20, 30].iter().enumerate() {
+                    assert_eq!(*mutec.lock(index), *item, "Change was not visible at index: {index}");
+                }
+            }
         }
         mod async_iter {
             use super::super::super::Mutec;
@@ -450,6 +1325,34 @@ mod tests {
                 assert_eq!(iter.next(), None);
                 assert_eq!(iter.next(), None);
             }
+            #[test]
+            fn par_for_each() {
+                let mutec: Mutec<usize> = (0..10000).collect();
+                mutec.par_for_each(8, |value| *value += 1);
+                for (index, value) in mutec.iter().enumerate() {
+                    assert_eq!(*value, index+1, "Value at index: {index} was not incremented once");
+                }
+            }
+            #[test]
+            fn par_map() {
+                let mutec: Mutec<usize> = (0..1000).collect();
+                let out = mutec.par_map(4, |value| *value*2);
+                assert_eq!(out, (0..1000).map(|value| value*2).collect::<Vec<usize>>());
+            }
+            #[test]
+            fn par_for_each_contended() {
+                let mutec: Mutec<usize> = Mutec::from([0, 0, 0]);
+                let guard = mutec.lock(1);
+                std::thread::scope(|s| {
+                    let handle = s.spawn(|| mutec.par_for_each(2, |value| *value += 1));
+                    std::thread::sleep(std::time::Duration::from_millis(20));
+                    drop(guard);
+                    handle.join().unwrap();
+                });
+                for index in 0..3 {
+                    assert_eq!(*mutec.lock(index), 1, "Value at index: {index} was not incremented once");
+                }
+            }
             /*#[test]
             fn order() {
                 // 1: 1(hold)
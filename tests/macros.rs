@@ -24,6 +24,15 @@ mod tests {
             let value: Option<usize> = Some(64);
             assert_pattern!(value, None, "is");
         }
+        #[test]
+        fn expression() {
+            assert_pattern!("64".parse::<usize>(), Ok(64));
+        }
+        #[test]
+        #[should_panic(expected = "Item did not match variant")]
+        fn expression_fail() {
+            assert_pattern!("sixty four".parse::<usize>(), Ok(_));
+        }
     }
     mod assert_pattern_ne {
         use abes_nice_things::assert_pattern_ne;
@@ -49,5 +58,92 @@ mod tests {
             let value: Option<usize> = None;
             assert_pattern_ne!(value, None, "Unnecessary");
         }
+        #[test]
+        fn expression() {
+            assert_pattern_ne!("sixty four".parse::<usize>(), Ok(_));
+        }
+    }
+    mod matches_pattern {
+        use abes_nice_things::{matches_pattern, not_matches_pattern};
+        #[test]
+        fn matches() {
+            let value: Option<usize> = Some(64);
+            assert!(matches_pattern!(value, Some(_)));
+            assert!(!not_matches_pattern!(value, Some(_)));
+        }
+        #[test]
+        fn not_matches() {
+            let value: Option<usize> = None;
+            assert!(!matches_pattern!(value, Some(_)));
+            assert!(not_matches_pattern!(value, Some(_)));
+        }
+        #[test]
+        fn expression() {
+            assert!(matches_pattern!("64".parse::<usize>(), Ok(64)));
+            assert!(not_matches_pattern!("64".parse::<usize>(), Err(_)));
+        }
+    }
+    mod try_pattern {
+        use abes_nice_things::try_pattern;
+        #[test]
+        fn success() {
+            let value: Option<usize> = Some(64);
+            assert_eq!(try_pattern!(value, Some(_)), Ok(()));
+        }
+        #[test]
+        fn fail() {
+            let value: Option<usize> = None;
+            assert_eq!(try_pattern!(value, Some(_)), Err(()));
+        }
+        #[test]
+        fn fail_message() {
+            let value: Option<usize> = None;
+            assert_eq!(try_pattern!(value, Some(_), "Nothing"), Err("Nothing"));
+        }
+        #[test]
+        fn chain() {
+            fn check(first: Option<usize>, second: Option<usize>) -> Result<(), ()> {
+                try_pattern!(first, Some(_))?;
+                try_pattern!(second, None)?;
+                Ok(())
+            }
+            assert_eq!(check(Some(1), None), Ok(()));
+            assert_eq!(check(None, None), Err(()));
+            assert_eq!(check(Some(1), Some(2)), Err(()));
+        }
+    }
+    mod unwrap_pattern {
+        use abes_nice_things::unwrap_pattern;
+        #[test]
+        fn success() {
+            let value: Option<usize> = Some(64);
+            assert_eq!(unwrap_pattern!(value, Some(x) => x), 64);
+        }
+        #[test]
+        fn multiple_bindings() {
+            let value: Result<(usize, &str), ()> = Ok((2, "two"));
+            assert_eq!(unwrap_pattern!(value, Ok((num, name)) => format!("{num} {name}")), "2 two");
+        }
+        #[test]
+        #[should_panic(expected = "Item did not match pattern: Some(x)")]
+        fn fail() {
+            let value: Option<usize> = None;
+            unwrap_pattern!(value, Some(x) => x);
+        }
+        #[test]
+        #[should_panic(expected = "Totally")]
+        fn fail_message() {
+            let value: Option<usize> = None;
+            unwrap_pattern!(value, Some(x) => x, "Totally");
+        }
+    }
+    mod debug_eprintln {
+        use abes_nice_things::debug_eprintln;
+        #[test]
+        fn usage() {
+            debug_eprintln!();
+            debug_eprintln!("Printed to stderr");
+            debug_eprintln!("Printed {} to {}", "formatted", "stderr");
+        }
     }
-}
+}
\ No newline at end of file